@@ -0,0 +1,52 @@
+use std::env;
+use std::fs::{read_dir, write};
+use std::path::{Path, PathBuf};
+
+/// Generates the registry of solutions: every `src/days/yYYYY/dayNN.rs` becomes module `yYYYY::dayNN`,
+/// and its `DayN` is handed out by `get_day(YYYY, N)`.
+fn main() {
+    // Cargo scans the directory for changes, so adding a day triggers a new registry.
+    println!("cargo:rerun-if-changed=src/days");
+    let days_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/days");
+
+    let mut registry = String::new();
+    let mut year_arms = String::new();
+
+    for (year, year_dir) in numbered_entries(&days_dir, "y", "") {
+        let days = numbered_entries(&year_dir, "day", ".rs");
+
+        registry.push_str(&format!("pub mod y{} {{\n    use crate::days::Solution;\n\n", year));
+        for (day, path) in &days {
+            registry.push_str(&format!("    #[path = {:?}]\n    pub mod day{:02};\n", path.display().to_string(), day));
+        }
+        registry.push_str("\n    pub fn get_day(day: i32) -> Result<Box<dyn Solution>, String> {\n        match day {\n");
+        for (day, _) in &days {
+            registry.push_str(&format!("            {day} => Ok(Box::new(day{day:02}::Day{day})),\n", day = day));
+        }
+        registry.push_str(&format!("            _ => Err(format!(\"No implementation yet for day {{}} of {}\", day))\n        }}\n    }}\n}}\n\n", year));
+
+        year_arms.push_str(&format!("        {year} => y{year}::get_day(day),\n", year = year));
+    }
+
+    registry.push_str("pub fn get_day(year: i32, day: i32) -> Result<Box<dyn Solution>, String> {\n    match year {\n");
+    registry.push_str(&year_arms);
+    registry.push_str("        _ => Err(format!(\"No solutions yet for {}\", year))\n    }\n}\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("registry.rs");
+    write(out_path, registry).unwrap();
+}
+
+/// The entries of a directory named `<prefix><number><suffix>`, ordered by their number.
+fn numbered_entries(dir: &Path, prefix: &str, suffix: &str) -> Vec<(u32, PathBuf)> {
+    let mut entries: Vec<_> = read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?.parse().ok()?;
+            Some((number, path))
+        })
+        .collect();
+
+    entries.sort();
+    entries
+}
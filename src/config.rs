@@ -0,0 +1,176 @@
+use std::env::current_dir;
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use toml::{Table, Value};
+use crate::days::DEFAULT_YEAR;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// JSON lines, one per day and part.
+    Json,
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Unknown output format '{}'", s)),
+        }
+    }
+}
+
+/// Settings from `aoc.toml`. All paths are absolute, or relative to `root`: the directory holding
+/// the config file. Without a config file, the defaults apply relative to the working directory.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Config {
+    pub root: PathBuf,
+    pub resources_dir: PathBuf,
+    pub templates_dir: PathBuf,
+    pub default_year: i32,
+    pub session_file: PathBuf,
+    pub format: OutputFormat,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::with_root(PathBuf::new())
+    }
+}
+
+impl Config {
+    fn with_root(root: PathBuf) -> Self {
+        Self {
+            resources_dir: root.join("resources"),
+            templates_dir: root.join("resources/templates"),
+            default_year: DEFAULT_YEAR,
+            session_file: root.join(".aoc_session"),
+            format: OutputFormat::default(),
+            root,
+        }
+    }
+
+    /// Reads the config file. The `root` is where relative paths in it are taken from.
+    pub fn parse(content: &str, root: &Path) -> Result<Self, String> {
+        let table: Table = content.parse().map_err(|e| format!("Could not parse {}: {}", CONFIG_FILE, e))?;
+        let mut config = Self::with_root(root.to_path_buf());
+
+        for (key, value) in &table {
+            match key.as_str() {
+                "resources_dir" => config.resources_dir = root.join(string_value(key, value)?),
+                "templates_dir" => config.templates_dir = root.join(string_value(key, value)?),
+                "session_file" => config.session_file = root.join(string_value(key, value)?),
+                "default_year" => match value {
+                    Value::Integer(year) => config.default_year = i32::try_from(*year).map_err(|e| format!("Invalid default_year in {}: {}", CONFIG_FILE, e))?,
+                    _ => return Err(format!("Expected a number for default_year in {}", CONFIG_FILE)),
+                },
+                "format" => config.format = string_value(key, value)?.parse()?,
+                _ => return Err(format!("Unknown setting '{}' in {}", key, CONFIG_FILE)),
+            }
+        }
+
+        // Templates live in the resources by default, so they move along with them.
+        if !table.contains_key("templates_dir") {
+            config.templates_dir = config.resources_dir.join("templates");
+        }
+
+        Ok(config)
+    }
+
+    /// Looks for `aoc.toml` in the working directory and its parents, and reads the first one found.
+    pub fn discover() -> Result<Self, String> {
+        let cwd = current_dir().map_err(|e| format!("Could not get working directory: {}", e))?;
+
+        match cwd.ancestors().map(|dir| dir.join(CONFIG_FILE)).find(|path| path.is_file()) {
+            Some(path) => {
+                let content = read_to_string(&path).map_err(|e| format!("Could not read {:?}: {}", path, e))?;
+                Self::parse(&content, path.parent().unwrap_or(&cwd))
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Makes the config available through `Config::get`. Only the first call has any effect.
+    pub fn init(config: Config) {
+        let _ = CONFIG.set(config);
+    }
+
+    /// The config set by `init`, or the defaults if there is none (e.g. in tests).
+    pub fn get() -> &'static Config {
+        CONFIG.get_or_init(Config::default)
+    }
+
+    /// The directory holding the sources of the days of a year.
+    pub fn days_dir(&self, year: i32) -> PathBuf {
+        self.root.join("src/days").join(format!("y{}", year))
+    }
+}
+
+fn string_value<'a>(key: &str, value: &'a Value) -> Result<&'a str, String> {
+    value.as_str().ok_or(format!("Expected a string for {} in {}", key, CONFIG_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use crate::config::{Config, OutputFormat};
+    use crate::days::DEFAULT_YEAR;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("\
+            resources_dir = \"data\"\n\
+            default_year = 2023\n\
+            session_file = \"/secrets/aoc\"\n\
+            format = \"json\"\n\
+        ", Path::new("/repo")).unwrap();
+
+        assert_eq!(config, Config {
+            root: PathBuf::from("/repo"),
+            resources_dir: PathBuf::from("/repo/data"),
+            templates_dir: PathBuf::from("/repo/data/templates"),
+            default_year: 2023,
+            session_file: PathBuf::from("/secrets/aoc"),
+            format: OutputFormat::Json,
+        });
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let config = Config::parse("", Path::new("/repo")).unwrap();
+
+        assert_eq!(config.resources_dir, PathBuf::from("/repo/resources"));
+        assert_eq!(config.templates_dir, PathBuf::from("/repo/resources/templates"));
+        assert_eq!(config.default_year, DEFAULT_YEAR);
+        assert_eq!(config.days_dir(2024), PathBuf::from("/repo/src/days/y2024"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Config::parse("resources_dir = 42", Path::new("/repo")).is_err());
+        assert!(Config::parse("default_year = \"2024\"", Path::new("/repo")).is_err());
+        assert!(Config::parse("format = \"xml\"", Path::new("/repo")).is_err());
+        assert!(Config::parse("colour = \"blue\"", Path::new("/repo")).is_err());
+        assert!(Config::parse("not toml", Path::new("/repo")).is_err());
+    }
+}
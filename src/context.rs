@@ -0,0 +1,471 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use crate::util::image::{create_animation, save_image, GifAnimation, Image, ImageFormat};
+use crate::util::number::parse_usize;
+use crate::util::player::{has_terminal, Player};
+
+/// How much diagnostic output the days give. Answers are not affected by it.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Default)]
+pub enum LogLevel {
+    Quiet,
+    #[default]
+    Info,
+    Debug,
+}
+
+/// A snapshot of what a day is working on, for a `Sink` to show.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Frame {
+    Image(Image),
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Frame::Image(image) => write!(f, "{}", image.to_blocks()),
+        }
+    }
+}
+
+/// Receives the frames a day visualizes.
+pub trait Sink {
+    fn frame(&mut self, label: &str, frame: &Frame);
+
+    /// Called before the frames of a part of a day come in.
+    fn start_part(&mut self, _day: i32, _part: usize) {}
+
+    /// Called once the days are done, for a sink to complete what it wrote.
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Shows frames on stderr, so they never end up between the answers.
+pub struct TerminalSink;
+
+impl Sink for TerminalSink {
+    fn frame(&mut self, label: &str, frame: &Frame) {
+        eprintln!("{}", label);
+        eprintln!("{}", frame);
+    }
+}
+
+/// Saves image frames: each to its own file, named after the path, the part and the frame label,
+/// or all frames of a part in one animation when the path is a GIF.
+pub struct ImageSink {
+    path: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    part: Option<String>,
+    animation: Option<(PathBuf, GifAnimation<BufWriter<File>>)>,
+    saved: usize,
+    error: Option<String>,
+}
+
+impl ImageSink {
+    /// Frames of an animation show for this many hundredths of a second.
+    const DELAY: u16 = 10;
+
+    pub fn new<P: Into<PathBuf>>(path: P) -> Result<Self, String> {
+        let path = path.into();
+        let format = ImageFormat::from_path(&path)?;
+        Ok(Self { path, format, scale: 1, part: None, animation: None, saved: 0, error: None })
+    }
+
+    /// Saves the frames blown up, every pixel becoming a square of `scale` pixels wide.
+    pub fn with_scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    fn save(&mut self, label: &str, image: &Image) -> Result<(), String> {
+        let image = &image.scaled(self.scale);
+        if self.format != ImageFormat::Gif {
+            return save_image(self.file_path(&[label]), image);
+        }
+
+        if self.animation.is_none() {
+            let path = self.file_path(&[]);
+            let animation = create_animation(&path, image.width, image.height, Self::DELAY)?;
+            self.animation = Some((path, animation));
+        }
+        self.animation.as_mut().map_or(Ok(()), |(_, animation)| animation.add_frame(image))
+    }
+
+    fn finish_animation(&mut self) -> Result<(), String> {
+        match self.animation.take() {
+            Some((path, animation)) => animation.finish()?.flush().map_err(|e| format!("Could not write {:?}: {}", path, e)),
+            None => Ok(()),
+        }
+    }
+
+    /// `frames/aoc.png` with label `At 7138` in part 2 of day 14 becomes `frames/aoc_day14_part2_at_7138.png`.
+    fn file_path(&self, labels: &[&str]) -> PathBuf {
+        let stem = self.path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+        let mut names = vec![stem.to_string()];
+        names.extend(self.part.iter().cloned());
+        for label in labels {
+            names.push(match file_name_part(label) {
+                name if name.is_empty() => format!("{:05}", self.saved),
+                name => name,
+            });
+        }
+
+        let extension = self.path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        self.path.with_file_name(format!("{}.{}", names.join("_"), extension))
+    }
+}
+
+/// Keeps the letters and digits of the label, joining them with underscores.
+fn file_name_part(label: &str) -> String {
+    label.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect::<String>()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+impl Sink for ImageSink {
+    fn frame(&mut self, label: &str, frame: &Frame) {
+        let Frame::Image(image) = frame;
+        // Once saving failed, there is no point in trying the other frames; finish reports it.
+        if self.error.is_some() {
+            return;
+        }
+
+        match self.save(label, image) {
+            Ok(_) => self.saved += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn start_part(&mut self, day: i32, part: usize) {
+        // Every part gets an animation of its own, its frames may well have another size.
+        if let Err(e) = self.finish_animation() {
+            self.error.get_or_insert(e);
+        }
+        self.part = Some(format!("day{:02}_part{}", day, part));
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.finish_animation()?;
+
+        match self.saved {
+            0 => Err("No image frames to save".to_string()),
+            saved => {
+                eprintln!("Saved {} frame(s) next to {:?}", saved, self.path);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Collects the frames of a part of a day, and replays them in the terminal once the part is done.
+pub struct PlayerSink {
+    title: String,
+    frames: Vec<(String, Image)>,
+    error: Option<String>,
+}
+
+impl PlayerSink {
+    pub fn new() -> Result<Self, String> {
+        if !has_terminal() {
+            return Err("Frames can only be played in a terminal".to_string());
+        }
+        Ok(Self { title: String::new(), frames: vec![], error: None })
+    }
+
+    fn play(&mut self) -> Result<(), String> {
+        match self.frames.is_empty() {
+            true => Ok(()),
+            false => Player::new(std::mem::take(&mut self.frames)).play(&self.title),
+        }
+    }
+}
+
+impl Sink for PlayerSink {
+    fn frame(&mut self, label: &str, frame: &Frame) {
+        let Frame::Image(image) = frame;
+        self.frames.push((label.to_string(), image.clone()));
+    }
+
+    fn start_part(&mut self, day: i32, part: usize) {
+        if let Err(e) = self.play() {
+            self.error.get_or_insert(e);
+        }
+        self.title = format!("Day {} part {}", day, part);
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => self.play(),
+        }
+    }
+}
+
+/// Which steps of a simulation to visualize: `all`, or a comma separated list of steps (`100`)
+/// and ranges (`1..10`, `1..=10`).
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Steps {
+    All,
+    Ranges(Vec<(usize, usize)>),
+}
+
+impl Steps {
+    pub fn contains(&self, step: usize) -> bool {
+        match self {
+            Steps::All => true,
+            Steps::Ranges(ranges) => ranges.iter().any(|(start, end)| (*start..=*end).contains(&step)),
+        }
+    }
+}
+
+impl FromStr for Steps {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Steps::All);
+        }
+
+        let ranges = s.split(',').map(|part| {
+            if let Some((start, end)) = part.split_once("..=") {
+                Ok((parse_usize(start)?, parse_usize(end)?))
+            } else if let Some((start, end)) = part.split_once("..") {
+                let end = parse_usize(end)?.checked_sub(1).ok_or(format!("Empty range '{}'", part))?;
+                Ok((parse_usize(start)?, end))
+            } else {
+                parse_usize(part).map(|step| (step, step))
+            }
+        }).collect::<Result<_, String>>()?;
+
+        Ok(Steps::Ranges(ranges))
+    }
+}
+
+/// Handed to the puzzles of a day for everything that is not the answer: log messages go to stderr
+/// depending on the log level, frames go to the sink when there is one. Only an interactive context
+/// allows a day to ask the user for help.
+#[derive(Default)]
+pub struct Context {
+    level: LogLevel,
+    sink: Option<RefCell<Box<dyn Sink>>>,
+    steps: Option<Steps>,
+    interactive: bool,
+}
+
+impl Context {
+    pub fn new(level: LogLevel) -> Self {
+        Self { level, ..Self::default() }
+    }
+
+    /// A context that drops all output, e.g. for benchmarking.
+    pub fn quiet() -> Self {
+        Self::new(LogLevel::Quiet)
+    }
+
+    pub fn with_sink(mut self, sink: Box<dyn Sink>) -> Self {
+        self.sink = Some(RefCell::new(sink));
+        self
+    }
+
+    pub fn with_steps(mut self, steps: Option<Steps>) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// The steps to visualize, when chosen; otherwise a day shows whatever it sees fit.
+    pub fn steps(&self) -> Option<&Steps> {
+        self.steps.as_ref()
+    }
+
+    pub fn with_interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Whether messages of the level are shown, for a day to skip work only needed for them.
+    pub fn is_enabled(&self, level: LogLevel) -> bool {
+        self.level >= level
+    }
+
+    /// Messages are only made when they are shown, so they can be costly to put together.
+    pub fn debug<M: Display, F: FnOnce() -> M>(&self, message: F) {
+        if self.is_enabled(LogLevel::Debug) {
+            eprintln!("{}", message());
+        }
+    }
+
+    pub fn is_visualizing(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    /// Passes a frame to the sink. The frame is only made when there is a sink to show it.
+    pub fn visualize<L: Display, F: FnOnce() -> Frame>(&self, label: L, frame: F) {
+        if let Some(sink) = &self.sink {
+            sink.borrow_mut().frame(&label.to_string(), &frame());
+        }
+    }
+
+    /// Passes a frame of a step of a simulation to the sink, when the step is one of the chosen ones.
+    pub fn visualize_step<L: Display, F: FnOnce() -> Frame>(&self, step: usize, label: L, frame: F) {
+        if self.steps.as_ref().is_some_and(|steps| steps.contains(step)) {
+            self.visualize(label, frame);
+        }
+    }
+
+    /// Tells the sink that the frames that follow are of this part of the day.
+    pub fn start_part(&self, day: i32, part: usize) {
+        if let Some(sink) = &self.sink {
+            sink.borrow_mut().start_part(day, part);
+        }
+    }
+
+    /// Lets the sink complete its output, e.g. write out an animation.
+    pub fn finish(&self) -> Result<(), String> {
+        match &self.sink {
+            Some(sink) => sink.borrow_mut().finish(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::env::temp_dir;
+    use std::fs::{read, remove_dir_all};
+    use crate::context::{Context, Frame, ImageSink, LogLevel, Sink, Steps};
+    use crate::util::image::{Color, Image};
+
+    struct Recorder(Rc<RefCell<Vec<(String, Frame)>>>);
+
+    impl Sink for Recorder {
+        fn frame(&mut self, label: &str, frame: &Frame) {
+            self.0.borrow_mut().push((label.to_string(), frame.clone()));
+        }
+    }
+
+    #[test]
+    fn test_log_levels() {
+        assert!(LogLevel::Quiet < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Debug);
+        assert!(Context::default().is_enabled(LogLevel::Info));
+        assert!(!Context::default().is_enabled(LogLevel::Debug));
+        assert!(Context::new(LogLevel::Debug).is_enabled(LogLevel::Debug));
+        assert!(!Context::quiet().is_enabled(LogLevel::Info));
+
+        Context::default().debug(|| -> String { panic!("No message should be made when it is not shown") });
+    }
+
+    #[test]
+    fn test_visualize() {
+        let frames = Rc::new(RefCell::new(vec![]));
+
+        let context = Context::default();
+        assert!(!context.is_visualizing());
+        context.visualize("t = 1", || panic!("No frame should be made without a sink"));
+
+        let context = Context::default().with_sink(Box::new(Recorder(frames.clone())));
+        assert!(context.is_visualizing());
+        context.visualize("t = 1", || Frame::Image(Image::new(1, 1, Color::WHITE)));
+        context.visualize(format!("t = {}", 2), || Frame::Image(Image::new(1, 1, Color::BLACK)));
+
+        assert_eq!(*frames.borrow(), vec![
+            ("t = 1".to_string(), Frame::Image(Image::new(1, 1, Color::WHITE))),
+            ("t = 2".to_string(), Frame::Image(Image::new(1, 1, Color::BLACK))),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_steps() {
+        assert_eq!("all".parse(), Ok(Steps::All));
+        assert_eq!("5,1..3,7..=8".parse(), Ok(Steps::Ranges(vec![(5, 5), (1, 2), (7, 8)])));
+        assert!("5..".parse::<Steps>().is_err());
+        assert!("3..0".parse::<Steps>().is_err());
+
+        let steps: Steps = "5,1..3".parse().unwrap();
+        assert!(steps.contains(1) && steps.contains(2) && steps.contains(5));
+        assert!(!steps.contains(3) && !steps.contains(0));
+        assert!(Steps::All.contains(12345));
+    }
+
+    #[test]
+    fn test_visualize_step() {
+        let frames = Rc::new(RefCell::new(vec![]));
+        let context = Context::default()
+            .with_sink(Box::new(Recorder(frames.clone())))
+            .with_steps(Some("2..4".parse().unwrap()));
+
+        for step in 0..10 {
+            context.visualize_step(step, format!("Step {}", step), || Frame::Image(Image::new(1, 1, Color::WHITE)));
+        }
+        assert_eq!(frames.borrow().iter().map(|(label, _)| label.as_str()).collect::<Vec<_>>(), vec!["Step 2", "Step 3"]);
+
+        // Without chosen steps, a day picks what to show by itself.
+        Context::default().with_sink(Box::new(Recorder(frames.clone())))
+            .visualize_step(1, "Step 1", || panic!("No steps were chosen"));
+    }
+
+    #[test]
+    fn test_image_sink() {
+        let dir = temp_dir().join(format!("image-sink-test-{}", std::process::id()));
+        let image = Image::new(2, 2, Color::WHITE);
+
+        let mut sink = ImageSink::new(dir.join("day14.ppm")).unwrap();
+        sink.frame("At 7138", &Frame::Image(image.clone()));
+        assert_eq!(sink.finish(), Ok(()));
+        assert_eq!(read(dir.join("day14_at_7138.ppm")).unwrap(), image.to_ppm());
+
+        let mut sink = ImageSink::new(dir.join("day14.pgm")).unwrap().with_scale(3);
+        sink.frame("", &Frame::Image(image.clone()));
+        assert_eq!(sink.finish(), Ok(()));
+        assert_eq!(read(dir.join("day14_00000.pgm")).unwrap(), image.scaled(3).to_pgm());
+
+        let mut sink = ImageSink::new(dir.join("aoc.png")).unwrap();
+        sink.start_part(14, 2);
+        sink.frame("At 7138", &Frame::Image(image.clone()));
+        assert_eq!(sink.finish(), Ok(()));
+        assert!(dir.join("aoc_day14_part2_at_7138.png").exists());
+
+        let mut sink = ImageSink::new(dir.join("day14.gif")).unwrap();
+        sink.frame("At 1", &Frame::Image(image.clone()));
+        sink.frame("At 2", &Frame::Image(Image::new(2, 2, Color::BLACK)));
+        assert_eq!(sink.finish(), Ok(()));
+        assert_eq!(&read(dir.join("day14.gif")).unwrap()[..6], b"GIF89a");
+
+        // Parts get an animation each, so their frames can differ in size.
+        let mut sink = ImageSink::new(dir.join("aoc.gif")).unwrap();
+        sink.start_part(15, 1);
+        sink.frame("Move 1", &Frame::Image(image.clone()));
+        sink.start_part(15, 2);
+        sink.frame("Move 1", &Frame::Image(Image::new(4, 2, Color::BLACK)));
+        assert_eq!(sink.finish(), Ok(()));
+        assert!(dir.join("aoc_day15_part1.gif").exists() && dir.join("aoc_day15_part2.gif").exists());
+
+        let mut sink = ImageSink::new(dir.join("day14.gif")).unwrap();
+        sink.frame("At 1", &Frame::Image(image));
+        sink.frame("At 2", &Frame::Image(Image::new(3, 2, Color::BLACK)));
+        assert!(sink.finish().is_err());
+
+        assert!(ImageSink::new(dir.join("day14.txt")).is_err());
+        assert!(ImageSink::new(dir.join("day14.png")).unwrap().finish().is_err());
+        remove_dir_all(dir).unwrap();
+    }
+}
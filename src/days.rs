@@ -1,88 +1,90 @@
-mod day01;
-use day01::DAY1;
-mod day02;
-use day02::DAY2;
-mod day03;
-use day03::DAY3;
-mod day04;
-use day04::DAY4;
-mod day05;
-use day05::DAY5;
-mod day06;
-use day06::DAY6;
-mod day07;
-use day07::DAY7;
-mod day08;
-use day08::DAY8;
-mod day09;
-use day09::DAY9;
-mod day10;
-use day10::DAY10;
-mod day11;
-use day11::DAY11;
-mod day12;
-use day12::DAY12;
-mod day13;
-use day13::DAY13;
-mod day14;
-use day14::DAY14;
-mod day15;
-use day15::DAY15;
-mod day16;
-use day16::DAY16;
-mod day17;
-use day17::DAY17;
-mod day18;
-use day18::DAY18;
-mod day19;
-use day19::DAY19;
-mod day20;
-use day20::DAY20;
-mod day21;
-use day21::DAY21;
-mod day22;
-use day22::DAY22;
-mod day23;
-use day23::DAY23;
-mod day24;
-use day24::DAY24;
-mod day25;
-use day25::DAY25;
-// « add day import »
-
-pub struct Day {
-    pub puzzle1: fn(input: &String),
-    pub puzzle2: fn(input: &String)
+use std::fmt;
+use crate::context::Context;
+
+/// The year used when none is given on the command line.
+pub const DEFAULT_YEAR: i32 = 2024;
+
+/// A day's solution: the input is parsed once, after which both puzzles run on the parsed input.
+/// The input type may borrow from the raw input, hence the lifetime. Anything besides the answer,
+/// like debug output or visualizations, goes through the context.
+pub trait Day {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String>;
+    fn part1(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String>;
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String>;
+}
+
+/// Object-safe counterpart of `Day`, so the registry can hand out any day's solution.
+pub trait Solution {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, String>;
+}
+
+/// A parsed input, ready to have its puzzles solved.
+pub trait Parsed {
+    fn part1(&self, ctx: &Context) -> Result<Answer, String>;
+    fn part2(&self, ctx: &Context) -> Result<Answer, String>;
+
+    fn part(&self, puzzle: usize, ctx: &Context) -> Result<Answer, String> {
+        match puzzle {
+            1 => self.part1(ctx),
+            2 => self.part2(ctx),
+            _ => Err(format!("There is no puzzle {}", puzzle))
+        }
+    }
+}
+
+struct ParsedInput<'a, D: Day>(D::Input<'a>);
+
+impl<D: Day> Parsed for ParsedInput<'_, D> {
+    fn part1(&self, ctx: &Context) -> Result<Answer, String> {
+        D::part1(&self.0, ctx)
+    }
+
+    fn part2(&self, ctx: &Context) -> Result<Answer, String> {
+        D::part2(&self.0, ctx)
+    }
 }
 
-pub fn get_day(day: i32) -> Result<Day, String> {
-    match day {
-        1 => Ok(DAY1),
-        2 => Ok(DAY2),
-        3 => Ok(DAY3),
-        4 => Ok(DAY4),
-        5 => Ok(DAY5),
-        6 => Ok(DAY6),
-        7 => Ok(DAY7),
-        8 => Ok(DAY8),
-        9 => Ok(DAY9),
-        10 => Ok(DAY10),
-        11 => Ok(DAY11),
-        12 => Ok(DAY12),
-        13 => Ok(DAY13),
-        14 => Ok(DAY14),
-        15 => Ok(DAY15),
-        16 => Ok(DAY16),
-        17 => Ok(DAY17),
-        18 => Ok(DAY18),
-        19 => Ok(DAY19),
-        20 => Ok(DAY20),
-        21 => Ok(DAY21),
-        22 => Ok(DAY22),
-        23 => Ok(DAY23),
-        24 => Ok(DAY24),
-        25 => Ok(DAY25),
-        // « add day match »
-        _ => Err(format!("No implementation yet for day {}", day))
+impl<D: Day + 'static> Solution for D {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, String> {
+        Ok(Box::new(ParsedInput::<D>(D::parse(input)?)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Answer {
+    Number(usize),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+// The year and day modules, and `get_day` handing out their solutions, are generated by `build.rs`
+// from the files in `src/days`.
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
@@ -1,22 +1,28 @@
 use std::collections::HashMap;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::parser::Parser;
 
-pub const DAY1: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day1;
 
-fn puzzle1(input: &String) {
-    let (left, right) = parse_input(input).unwrap();
+impl Day for Day1 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
 
-    println!("Diff between lists: {}", distance_between_lists(&left, &right).unwrap());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let (left, right) = input;
 
-fn puzzle2(input: &String) {
-    let (left, right) = parse_input(input).unwrap();
+        distance_between_lists(left, right).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let (left, right) = input;
 
-    println!("Similarity of lists: {}", calculate_similarity(&left, &right));
+        Ok(calculate_similarity(left, right).into())
+    }
 }
 
 fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<usize>), String> {
@@ -32,22 +38,22 @@ fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<usize>), String> {
     Ok((left, right))
 }
 
-fn distance_between_lists(left: &Vec<usize>, right: &Vec<usize>) -> Result<usize, String> {
+fn distance_between_lists(left: &[usize], right: &[usize]) -> Result<usize, String> {
     if left.len() != right.len() {
         return Err("Given lists are not of the same length".to_string());
     }
 
     // To get the distance, we take the lowest number on both sides and compare the difference.
     // The total distance is the sum of those numbers.
-    let mut sorted_left = left.clone();
+    let mut sorted_left = left.to_vec();
     sorted_left.sort();
-    let mut sorted_right = right.clone();
+    let mut sorted_right = right.to_vec();
     sorted_right.sort();
 
     Ok((0..left.len()).map(|idx| sorted_left[idx].abs_diff(sorted_right[idx])).sum())
 }
 
-fn calculate_similarity(left: &Vec<usize>, right: &Vec<usize>) -> usize {
+fn calculate_similarity(left: &[usize], right: &[usize]) -> usize {
     // Similarity is computed by taking each number in the left list, and multiplying its value by the number of occurrences in the right list.
     // To speed up this process, we create a lookup for right value => number of occurrences.
 
@@ -62,7 +68,7 @@ fn calculate_similarity(left: &Vec<usize>, right: &Vec<usize>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day01::{calculate_similarity, distance_between_lists, parse_input};
+    use crate::days::y2024::day01::{calculate_similarity, distance_between_lists, parse_input};
 
     const TEST_INPUT: &str = "\
         3   4\n\
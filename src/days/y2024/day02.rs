@@ -1,24 +1,26 @@
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::parser::Parser;
 
-pub const DAY2: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day2;
 
-fn puzzle1(input: &String) {
-    let reports = parse_input(input).unwrap();
+impl Day for Day2 {
+    type Input<'a> = Vec<Report>;
 
-    let safe_count = reports.iter().filter(|r| r.is_safe()).count();
-    println!("Of all reports, {} are safe.", safe_count);
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
 
-fn puzzle2(input: &String) {
-    let reports = parse_input(input).unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let safe_count = input.iter().filter(|r| r.is_safe()).count();
+        Ok(safe_count.into())
+    }
 
-    let safe_count = reports.iter().filter(|r| r.is_safe_dampening()).count();
-    println!("Of all reports, {} are safe with dampening.", safe_count);
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let safe_count = input.iter().filter(|r| r.is_safe_dampening()).count();
+        Ok(safe_count.into())
+    }
 }
 
 fn parse_input(input: &str) -> Result<Vec<Report>, String> {
@@ -26,7 +28,7 @@ fn parse_input(input: &str) -> Result<Vec<Report>, String> {
 }
 
 #[derive(Eq, PartialEq, Debug)]
-struct Report {
+pub struct Report {
     levels: Vec<usize>
 }
 
@@ -84,8 +86,9 @@ impl FromStr for Report {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
-    use crate::days::day02::{parse_input, Report};
+    use crate::days::y2024::day02::{parse_input, Report};
 
     const TEST_INPUT: &str = "\
         7 6 4 2 1\n\
@@ -1,20 +1,25 @@
 use regex::Regex;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::parser::Parser;
 
-pub const DAY3: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day3;
 
-fn puzzle1(input: &String) {
-    let result = puzzle1_parse(input).unwrap();
-    println!("Sum of all valid mul instructions: {}", result);
-}
+impl Day for Day3 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        // Both puzzles scan the raw memory differently, so there is nothing to parse up-front.
+        Ok(input)
+    }
 
-fn puzzle2(input: &String) {
-    let result = puzzle2_parse(input).unwrap();
-    println!("Sum of all valid mul instructions, accounting for conditionals: {}", result);
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        puzzle1_parse(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        puzzle2_parse(input).map(Answer::from)
+    }
 }
 
 fn puzzle1_parse(input: &str) -> Result<usize, String> {
@@ -84,7 +89,7 @@ fn puzzle2_parse(input: &str) -> Result<usize, String> {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day03::{puzzle1_parse, puzzle2_parse};
+    use crate::days::y2024::day03::{puzzle1_parse, puzzle2_parse};
 
     const TEST_INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
     const TEST_INPUT_2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
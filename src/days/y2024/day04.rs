@@ -1,23 +1,23 @@
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::geometry::{Directions, Grid, Point};
 
-pub const DAY4: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day4;
 
-fn puzzle1(input: &String) {
-    let grid = parse_input(input).unwrap();
-    let xmas_count = xmas_finder(&grid);
+impl Day for Day4 {
+    type Input<'a> = Grid<char>;
 
-    println!("There are {} occurrences of the word 'XMAS'", xmas_count);
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
 
-fn puzzle2(input: &String) {
-    let grid = parse_input(input).unwrap();
-    let x_mas_count = x_mas_finder(&grid);
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(xmas_finder(input).into())
+    }
 
-    println!("There are {} occurrences of X-MAS", x_mas_count);
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(x_mas_finder(input).into())
+    }
 }
 
 fn parse_input(input: &str) -> Result<Grid<char>, String> {
@@ -41,11 +41,8 @@ fn xmas_finder(puzzle: &Grid<char>) -> usize {
 
     for (p, _) in starting_points {
         for dir in &search_directions {
-            let chars: Vec<char> = puzzle.get_in_direction(&p, *dir).iter().take(3).map(|c| *c).collect();
-            match chars[..] {
-                ['M', 'A', 'S'] => found = found + 1,
-                _ => {}
-            }
+            let chars: Vec<char> = puzzle.get_in_direction(&p, *dir).iter().take(3).copied().collect();
+            if let ['M', 'A', 'S'] = chars[..] { found += 1 }
         }
     }
 
@@ -67,10 +64,7 @@ fn x_mas_finder(puzzle: &Grid<char>) -> usize {
         let tlbr: Result<[char; 2], _> = puzzle.get_adjacent(&p, Directions::TLBR).try_into();
         let trbl: Result<[char; 2], _> = puzzle.get_adjacent(&p, Directions::TRBL).try_into();
 
-        match (tlbr, trbl) {
-            (Ok(['M', 'S'] | ['S', 'M']), Ok(['M', 'S'] | ['S', 'M'])) => found = found + 1,
-            _ => {}
-        }
+        if let (Ok(['M', 'S'] | ['S', 'M']), Ok(['M', 'S'] | ['S', 'M'])) = (tlbr, trbl) { found += 1 }
     }
 
     found
@@ -78,7 +72,7 @@ fn x_mas_finder(puzzle: &Grid<char>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day04::{parse_input, x_mas_finder, xmas_finder};
+    use crate::days::y2024::day04::{parse_input, x_mas_finder, xmas_finder};
 
     const TEST_INPUT: &str = "\
         MMMSXXMASM\n\
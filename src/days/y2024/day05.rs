@@ -1,25 +1,27 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::number::parse_usize;
 use crate::util::parser::Parser;
 
-pub const DAY5: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day5;
 
-fn puzzle1(input: &String) {
-    let queue: PrintQueue = input.parse().unwrap();
+impl Day for Day5 {
+    type Input<'a> = PrintQueue;
 
-    println!("Sum of middle-pages of valid updates: {}", queue.puzzle_1_solution());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let queue: PrintQueue = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.puzzle_1_solution().into())
+    }
 
-    println!("Sum of middle-pages of fixed updates: {}", queue.puzzle_2_solution());
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.puzzle_2_solution().into())
+    }
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -29,13 +31,13 @@ struct PrintRule {
 }
 
 #[derive(PartialEq, Eq, Debug)]
-struct PrintQueue {
+pub struct PrintQueue {
     rules: Vec<PrintRule>,
     updates: Vec<Vec<usize>>
 }
 
 impl PrintQueue {
-    fn in_order(&self, update: &Vec<usize>) -> bool {
+    fn in_order(&self, update: &[usize]) -> bool {
         for i in 0..update.len() {
             let current = &update[i];
 
@@ -51,7 +53,7 @@ impl PrintQueue {
         true
     }
 
-    fn sort_update(&self, update: &mut Vec<usize>) {
+    fn sort_update(&self, update: &mut [usize]) {
         // Can we build a sorting rule using the print rules? Would it perform?
         update.sort_by(|l, r| {
             // We should see if there is a rule with these two numbers. If so, we know the order; otherwise assume equal?
@@ -84,9 +86,44 @@ impl PrintQueue {
     }
 }
 
+impl FromStr for PrintRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+
+        let first = parser.usize()?;
+        parser.literal("|")?;
+        let second = parser.usize()?;
+        parser.ensure_exhausted()?;
+
+        Ok(Self { first, second })
+    }
+}
+
+impl FromStr for PrintQueue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let result: Result<[_; 2], _> = s.split("\n\n").collect::<Vec<&str>>().try_into();
+        match result {
+            Ok([rules_input, updates_input]) => {
+                let rules = rules_input.lines().map(|l| l.parse()).collect::<Result<Vec<_>, _>>()?;
+                let updates = updates_input.lines().map(|l| l.split(",").map(parse_usize).collect::<Result<Vec<_>, _>>()).collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Self { rules, updates })
+            }
+            _ => {
+                Err(format!("Could not parse print rules: {}", s))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
-    use crate::days::day05::{PrintQueue, PrintRule};
+    use crate::days::y2024::day05::{PrintQueue, PrintRule};
 
     const TEST_INPUT: &str = "\
         47|53\n\
@@ -175,38 +212,4 @@ mod tests {
 
         assert_eq!(queue.puzzle_2_solution(), 123);
     }
-}
-
-impl FromStr for PrintRule {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parser = Parser::new(s);
-
-        let first = parser.usize()?;
-        parser.literal("|")?;
-        let second = parser.usize()?;
-        parser.ensure_exhausted()?;
-
-        Ok(Self { first, second })
-    }
-}
-
-impl FromStr for PrintQueue {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let result: Result<[_; 2], _> = s.split("\n\n").collect::<Vec<&str>>().try_into();
-        match result {
-            Ok([rules_input, updates_input]) => {
-                let rules = rules_input.lines().map(|l| l.parse()).collect::<Result<Vec<_>, _>>()?;
-                let updates = updates_input.lines().map(|l| l.split(",").map(|v| parse_usize(v)).collect::<Result<Vec<_>, _>>()).collect::<Result<Vec<_>, _>>()?;
-
-                Ok(Self { rules, updates })
-            }
-            _ => {
-                Err(format!("Could not parse print rules: {}", s))
-            }
-        }
-    }
 }
\ No newline at end of file
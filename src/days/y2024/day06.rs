@@ -1,30 +1,32 @@
 use std::collections::{HashSet};
 use std::fmt::{Display, Formatter, Write};
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::{Context, Frame};
+use crate::days::{Answer, Day};
 use crate::util::geometry::{Directions, Grid, Point};
+use crate::util::image::{Color, Image};
+use crate::util::render::{Overlay, Renderer};
 
-pub const DAY6: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day6;
 
-fn puzzle1(input: &String) {
-    let map: Map = input.parse().unwrap();
+impl Day for Day6 {
+    type Input<'a> = Map;
 
-    let unique_tiles = map.count_guard_visited_tiles();
-    println!("The guard visited {} unique tiles", unique_tiles);
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let map: Map = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        Ok(input.count_guard_visited_tiles(ctx).into())
+    }
 
-    let unique_tiles = map.count_obstructable_tiles_for_loops();
-    println!("The guard can loop from {} unique tiles", unique_tiles);
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.count_obstructable_tiles_for_loops().into())
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Default)]
-enum Tile {
+pub enum Tile {
     #[default]
     Empty,
     Blocked,
@@ -50,22 +52,20 @@ impl Map {
         }
     }
 
-    fn count_guard_visited_tiles(&self) -> usize {
+    fn count_guard_visited_tiles(&self, ctx: &Context) -> usize {
         // Find the guard, and from there:
         // - Move in the current direction (start UPwards) util not possible
         // - Turn 90deg right, and continue
 
-        let guard_tile = self.entries().into_iter().find(|(_, t)| Tile::Guard.eq(t));
-        if guard_tile.is_none() { return 0; }
-
-        let mut guard_position = guard_tile.unwrap().0;
+        let Some(mut guard_position) = self.find(|t| Tile::Guard.eq(t)) else { return 0; };
         let mut guard_direction = Directions::Top;
 
         // We might divert from the previously known path and end up in a random other loop
         let mut seen_tiles: HashSet<Point> = HashSet::new();
 
-        loop {
+        for step in 0.. {
             seen_tiles.insert(guard_position);
+            ctx.visualize_step(step, format!("Step {}", step), || Frame::Image(render(self, &seen_tiles, guard_position)));
 
             let next_pos = guard_position.translate_in_direction(&guard_direction, 1);
             let next_tile = self.get(&next_pos);
@@ -81,6 +81,11 @@ impl Map {
             }
         }
 
+        // Without chosen steps (--frames) only the end of the walk is shown, there are thousands of steps.
+        if ctx.steps().is_none() {
+            ctx.visualize("Done", || Frame::Image(render(self, &seen_tiles, guard_position)));
+        }
+
         seen_tiles.len()
     }
 
@@ -124,10 +129,7 @@ impl Map {
         // go walk in a loop. Since the guard only turns right, we can determine if the guard ends up
         // at the same spot or not.
 
-        let guard_tile = self.entries().into_iter().find(|(_, t)| Tile::Guard.eq(t));
-        if guard_tile.is_none() { return 0; }
-
-        let mut guard_position = guard_tile.unwrap().0;
+        let Some(mut guard_position) = self.find(|t| Tile::Guard.eq(t)) else { return 0; };
         let mut guard_direction = Directions::Top;
 
         let mut seen_tiles: HashSet<Point> = HashSet::new();
@@ -159,9 +161,42 @@ impl Map {
     }
 }
 
+/// The map with the tiles the guard has visited, and the guard itself in red.
+fn render(map: &Map, seen_tiles: &HashSet<Point>, guard_position: Point) -> Image {
+    let renderer = Renderer::new(|tile| match tile {
+        Tile::Empty | Tile::Guard => Color::BLACK,
+        Tile::Blocked => Color::gray(128),
+    });
+    renderer.render(map, &[Overlay::new(seen_tiles.iter().copied(), Color::rgb(60, 90, 200)), Overlay::new([guard_position], Color::RED)])
+}
+
+impl FromStr for Tile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Tile::Empty),
+            "#" => Ok(Tile::Blocked),
+            "^" => Ok(Tile::Guard),
+            _ => Err(format!("Unknown tile: {}", s))
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Tile::Empty => '.',
+            Tile::Blocked => '#',
+            Tile::Guard => '^'
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day06::Map;
+    use crate::context::Context;
+    use crate::days::y2024::day06::Map;
 
     const TEST_INPUT: &str = "\
         ....#.....\n\
@@ -180,7 +215,7 @@ mod tests {
     fn test_count_guard_visited_tiles() {
         let map: Map = TEST_INPUT.parse().unwrap();
 
-        assert_eq!(map.count_guard_visited_tiles(), 41);
+        assert_eq!(map.count_guard_visited_tiles(&Context::default()), 41);
     }
 
     #[test]
@@ -189,27 +224,4 @@ mod tests {
 
         assert_eq!(map.count_obstructable_tiles_for_loops(), 6);
     }
-}
-
-impl FromStr for Tile {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Tile::Empty),
-            "#" => Ok(Tile::Blocked),
-            "^" => Ok(Tile::Guard),
-            _ => Err(format!("Unknown tile: {}", s))
-        }
-    }
-}
-
-impl Display for Tile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_char(match self {
-            Tile::Empty => '.',
-            Tile::Blocked => '#',
-            Tile::Guard => '^'
-        })
-    }
 }
\ No newline at end of file
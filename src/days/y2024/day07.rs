@@ -1,28 +1,30 @@
 use std::collections::VecDeque;
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::collection::CollectionExtension;
 use crate::util::parser::Parser;
 
-pub const DAY7: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day7;
 
-fn puzzle1(input: &String) {
-    let puzzle: Puzzle = input.parse().unwrap();
+impl Day for Day7 {
+    type Input<'a> = Puzzle;
 
-    println!("Sum of solvable equations: {}", puzzle.get_sum_of_solvable_equations(false));
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let puzzle: Puzzle = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_sum_of_solvable_equations(false).into())
+    }
 
-    println!("Sum of solvable equations with concatenation: {}", puzzle.get_sum_of_solvable_equations(true));
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_sum_of_solvable_equations(true).into())
+    }
 }
 
 #[derive(Debug)]
-struct Puzzle {
+pub struct Puzzle {
     equations: Vec<Equation>,
 }
 
@@ -41,7 +43,7 @@ struct Equation {
 impl Equation {
     fn concatenate(left: usize, right: usize) -> usize {
         let mut factor = 10;
-        while factor < right { factor = factor * 10; }
+        while factor < right { factor *= 10; }
 
         (left * factor) + right
     }
@@ -92,9 +94,38 @@ impl Equation {
     }
 }
 
+impl FromStr for Equation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let answer = parser.usize()?;
+        parser.literal(":")?;
+
+        let mut parts = vec![];
+        while !parser.is_exhausted()
+        {
+            parts.push(parser.usize()?);
+        }
+
+        Ok(Self { answer, parts })
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let equations = s.lines().map(|l| l.parse()).collect::<Result<_, _>>()?;
+
+        Ok(Self { equations })
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
-    use crate::days::day07::{Equation, Puzzle};
+    use crate::days::y2024::day07::{Equation, Puzzle};
 
     const TEST_INPUT: &str = "\
         190: 10 19\n\
@@ -138,32 +169,4 @@ mod tests {
         assert_eq!(Equation::concatenate(42, 1337), 421337);
         assert_eq!(Equation::concatenate(1, 0), 10);
     }
-}
-
-impl FromStr for Equation {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parser = Parser::new(s);
-        let answer = parser.usize()?;
-        parser.literal(":")?;
-
-        let mut parts = vec![];
-        while !parser.is_exhausted()
-        {
-            parts.push(parser.usize()?);
-        }
-
-        Ok(Self { answer, parts })
-    }
-}
-
-impl FromStr for Puzzle {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let equations = s.lines().map(|l| l.parse()).collect::<Result<_, _>>()?;
-
-        Ok(Self { equations })
-    }
 }
\ No newline at end of file
@@ -1,28 +1,30 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::collection::CollectionExtension;
 use crate::util::geometry::{Grid, Point};
 
-pub const DAY8: Day = Day {
-    puzzle1,
-    puzzle2,
-};
+pub struct Day8;
 
-fn puzzle1(input: &String) {
-    let map: Map = input.parse().unwrap();
+impl Day for Day8 {
+    type Input<'a> = Map;
 
-    println!("There are {} antinodes on the map.", map.count_antinodes());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let map: Map = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.count_antinodes().into())
+    }
 
-    println!("There are {} (harmonic) antinodes on the map.", map.count_all_antinodes());
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.count_all_antinodes().into())
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
-enum Tile {
+pub enum Tile {
     #[default]
     Void,
     Antenna(char),
@@ -36,12 +38,9 @@ impl Map {
 
         for point in self.points() {
             let tile = self.get(&point);
-            match tile {
-                Some(Tile::Antenna(value)) => {
-                    let list = result.get(&value).cloned().unwrap_or(vec![]);
-                    result.insert(value, list.append_item(&point));
-                }
-                _ => {}
+            if let Some(Tile::Antenna(value)) = tile {
+                let list = result.get(&value).cloned().unwrap_or(vec![]);
+                result.insert(value, list.append_item(&point));
             }
         }
 
@@ -83,16 +82,16 @@ impl Map {
         let mut cur_y = left.y;
         while self.bounds.contains(&(cur_x, cur_y).into()) {
             result.push((cur_x, cur_y).into());
-            cur_x = cur_x - dx;
-            cur_y = cur_y - dy;
+            cur_x -= dx;
+            cur_y -= dy;
         }
 
         cur_x = right.x;
         cur_y = right.y;
         while self.bounds.contains(&(cur_x, cur_y).into()) {
             result.push((cur_x, cur_y).into());
-            cur_x = cur_x + dx;
-            cur_y = cur_y + dy;
+            cur_x += dx;
+            cur_y += dy;
         }
 
         result
@@ -124,9 +123,21 @@ impl Map {
     }
 }
 
+impl FromStr for Tile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match &s.chars().collect::<Vec<_>>()[..] {
+            ['.'] => Ok(Tile::Void),
+            [value] => Ok(Tile::Antenna(*value)),
+            _ => Err(format!("Unknown tile: {}", s))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day08::Map;
+    use crate::days::y2024::day08::Map;
 
     const TEST_INPUT: &str = "\
         ............\n\
@@ -202,16 +213,4 @@ mod tests {
 
         assert_eq!(map.count_all_antinodes(), 34);
     }
-}
-
-impl FromStr for Tile {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match &s.chars().collect::<Vec<_>>()[..] {
-            ['.'] => Ok(Tile::Void),
-            [value] => Ok(Tile::Antenna(*value)),
-            _ => Err(format!("Unknown tile: {}", s))
-        }
-    }
 }
\ No newline at end of file
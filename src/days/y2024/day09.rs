@@ -1,24 +1,30 @@
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::number::parse_usize;
 
-pub const DAY9: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day9;
 
-fn puzzle1(input: &String) {
-    let mut drive: Drive = input.parse().unwrap();
+impl Day for Day9 {
+    type Input<'a> = Drive;
 
-    drive.defrag_blocks();
-    println!("Checksum of drive after block-defrag: {}", drive.checksum());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let mut drive = input.clone();
+
+        drive.defrag_blocks();
+        Ok(drive.checksum().into())
+    }
 
-fn puzzle2(input: &String) {
-    let mut drive: Drive = input.parse().unwrap();
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let mut drive = input.clone();
 
-    drive.defrag_files();
-    println!("Checksum of drive after file-defrag: {}", drive.checksum());
+        drive.defrag_files();
+        Ok(drive.checksum().into())
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -30,17 +36,14 @@ enum Block {
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
-struct Drive {
+pub struct Drive {
     blocks: Vec<Block>,
 }
 
 impl Drive {
     fn first_empty_block(&self) -> usize {
         for i in 0..self.blocks.len() {
-            match self.blocks[i] {
-                Block::Empty(_) => return i,
-                _ => {}
-            }
+            if let Block::Empty(_) = self.blocks[i] { return i }
         }
 
         self.blocks.len()
@@ -204,9 +207,35 @@ impl Drive {
     }
 }
 
+impl FromStr for Drive {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The drive representation is a list of digits that represent file-size and free-space alternating.
+        // The first digit is a file-size
+
+        let mut blocks = vec![];
+
+        for (file_id, i) in (0..s.len()).step_by(2).enumerate() {
+            let file_size = parse_usize(&s[i..(i+1)])?;
+
+            blocks.push(Block::File(file_id, file_size));
+
+            if i+1 < s.len() {
+                let offset= parse_usize(&s[(i+1)..(i+2)])?;
+                if offset > 0 {
+                    blocks.push(Block::Empty(offset));
+                }
+            }
+        }
+
+        Ok(Self { blocks })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day09::{Drive, Block};
+    use crate::days::y2024::day09::{Drive, Block};
 
     const TEST_INPUT: &str = "2333133121414131402";
 
@@ -298,32 +327,3 @@ mod tests {
         assert_eq!(drive.checksum(), 1928);
     }
 }
-
-impl FromStr for Drive {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // The drive representation is a list of digits that represent file-size and free-space alternating.
-        // The first digit is a file-size
-
-        let mut file_id = 0;
-        let mut blocks = vec![];
-
-        for i in (0..s.len()).step_by(2) {
-            let file_size = parse_usize(&s[i..(i+1)])?;
-
-            blocks.push(Block::File(file_id, file_size));
-
-            file_id += 1;
-
-            if i+1 < s.len() {
-                let offset= parse_usize(&s[(i+1)..(i+2)])?;
-                if offset > 0 {
-                    blocks.push(Block::Empty(offset));
-                }
-            }
-        }
-
-        Ok(Self { blocks })
-    }
-}
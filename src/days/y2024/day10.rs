@@ -1,61 +1,47 @@
-use std::collections::{HashSet, VecDeque};
-use crate::days::Day;
-use crate::util::geometry::{Directions, Grid, Point};
+use num_traits::ToPrimitive;
+use crate::context::Context;
+use crate::days::{Answer, Day};
+use crate::util::geometry::{DenseGrid, Directions, Point};
+use crate::util::search::bfs;
 
-pub const DAY10: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day10;
 
-fn puzzle1(input: &String) {
-    let map: TrailMap = input.parse().unwrap();
+impl Day for Day10 {
+    type Input<'a> = TrailMap;
 
-    println!("Total trailhead scores: {}", map.get_total_score());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let map: TrailMap = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_total_score().into())
+    }
 
-    println!("Total trailhead rating: {}", map.get_total_rating());
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_total_rating().into())
+    }
 }
 
-type TrailMap = Grid<usize>;
+type TrailMap = DenseGrid<usize>;
 
 impl TrailMap {
     fn get_trailhead_scores(&self) -> Vec<(Point, (usize, usize))> {
-        self.points().into_iter().filter_map(|p| {
-            match self.get(&p) {
-                Some(0) => Some((p, self.get_trailhead_score(p))),
-                _ => None
-            }
-        }).collect()
+        self.positions(|height| *height == 0).map(|p| (p, self.get_trailhead_score(p))).collect()
     }
 
     fn get_trailhead_score(&self, point: Point) -> (usize, usize) {
         // Get the number of (different) '9' tiles we can reach from the given point.
         // We can move up/down/left/right and only to a tile 1 higher from the current.
-
-        let mut peaks = HashSet::new();
-        let mut paths = 0;
-        let mut queue = VecDeque::new();
-        queue.push_back(point);
-
-        while let Some(point) = queue.pop_front() {
-            // Get points around this point with a value of 1 higher.
-            if let Some(cur_value) = self.get(&point) {
-                self.get_adjacent_entries(&point, Directions::NonDiagonal).into_iter().filter(|(_, v)| (cur_value + 1).eq(v))
-                    .for_each(|(p, v)| {
-                        if v == 9 {
-                            peaks.insert(p);
-                            paths += 1;
-                        } else {
-                            queue.push_back(p);
-                        }
-                    });
-            }
-        }
-
-        (peaks.len(), paths)
+        // That makes every trail to a peak equally long, so the trails are the shortest paths to the peaks.
+        let successors = |p: &Point| {
+            let next_height = self.get_ref(p).map(|height| height + 1);
+            self.neighbors(p, Directions::NonDiagonal).filter(move |(_, v)| next_height == Some(**v)).map(|(p, _)| p)
+        };
+        let search = bfs(point, successors, |_| false);
+
+        let peaks: Vec<Point> = search.distances().keys().filter(|p| self.get_ref(p) == Some(&9)).copied().collect();
+        // At most 4^9 trails lead to a peak, plenty of room in a usize.
+        (peaks.len(), search.count_paths_to(&peaks).to_usize().unwrap())
     }
 
     fn get_total_score(&self) -> usize {
@@ -69,7 +55,7 @@ impl TrailMap {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day10::TrailMap;
+    use crate::days::y2024::day10::TrailMap;
 
     const TEST_INPUT: &str = "\
         89010123\n\
@@ -1,22 +1,24 @@
 use std::collections::HashMap;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::number::parse_usize;
 
-pub const DAY11: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day11;
 
-fn puzzle1(input: &String) {
-    let stones = input.split(" ").map(parse_usize).collect::<Result<Vec<_>, _>>().unwrap();
+impl Day for Day11 {
+    type Input<'a> = Vec<usize>;
 
-    println!("After blinking 25 times, we see {} stones", run_stone_simulation(stones, 25).unwrap());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.split(" ").map(parse_usize).collect()
+    }
 
-fn puzzle2(input: &String) {
-    let stones = input.split(" ").map(parse_usize).collect::<Result<Vec<_>, _>>().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        run_stone_simulation(input.clone(), 25).map(Answer::from)
+    }
 
-    println!("After blinking 75 times, we see {} stones", run_stone_simulation(stones, 75).unwrap());
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        run_stone_simulation(input.clone(), 75).map(Answer::from)
+    }
 }
 
 fn run_stone_simulation(input: Vec<usize>, blinks: usize) -> Result<usize, String> {
@@ -47,7 +49,7 @@ fn get_number_of_stones_after(seed: usize, blinks: usize, cache: &mut HashMap<Ca
 
     let answer = match as_str.as_str() {
         "0" => get_number_of_stones_after(1, blinks - 1, cache)?,
-        _ if (as_str.len() % 2) == 0 => {
+        _ if as_str.len().is_multiple_of(2) => {
             let first_half = parse_usize(&as_str[..(as_str.len() / 2)])?;
             let second_half = parse_usize(&as_str[(as_str.len() / 2)..])?;
             get_number_of_stones_after(first_half, blinks - 1, cache)? + get_number_of_stones_after(second_half, blinks - 1, cache)?
@@ -62,7 +64,7 @@ fn get_number_of_stones_after(seed: usize, blinks: usize, cache: &mut HashMap<Ca
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
-    use crate::days::day11::{get_number_of_stones_after, run_stone_simulation};
+    use crate::days::y2024::day11::{get_number_of_stones_after, run_stone_simulation};
 
     #[test]
     fn test_get_number_of_stones_after() {
@@ -1,23 +1,25 @@
-use std::collections::{HashSet, VecDeque};
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::collection::CollectionExtension;
+use crate::util::disjoint_set::regions;
 use crate::util::geometry::{Directions, Grid, Point};
 
-pub const DAY12: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day12;
 
-fn puzzle1(input: &String) {
-    let garden: Garden = input.parse().unwrap();
+impl Day for Day12 {
+    type Input<'a> = Garden;
 
-    println!("The total cost of adding fences to this garden is {}", garden.get_total_price());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let garden: Garden = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_total_price().into())
+    }
 
-    println!("The bulk cost of adding fences to this garden is {}", garden.get_bulk_price());
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_bulk_price().into())
+    }
 }
 
 type Garden = Grid<char>;
@@ -39,48 +41,18 @@ struct Fence {
 impl Garden {
     fn get_regions(&self) -> Vec<Region> {
         // Count area and perimeter for every Region, duplicate names could occur
-        let mut regions: Vec<Region> = vec![];
-        let mut handled_cells: HashSet<Point> = HashSet::new();
-
-        for (point, name) in self.entries() {
-            if !handled_cells.insert(point) { continue; } // Already visited for a region
-
-            fn handle_side(garden: &Garden, point: Point, side: Directions, name: char, fences: &mut Vec<Fence>) -> Option<Point> {
-                let sibling = point.translate_in_direction(&side, 1);
+        let mut regions: Vec<Region> = regions(self.bounds, |a, b| self.get(a) == self.get(b)).into_iter().map(|points| {
+            let name = self.get(&points[0]).unwrap();
+            let mut fences = vec![];
 
-                match garden.get(&sibling) {
-                    Some(plot) if plot == name => {
-                        // Same region, return as new point to handle
-                        Some(sibling)
-                    },
-                    _ => {
+            for point in &points {
+                for side in [Directions::Top, Directions::Right, Directions::Bottom, Directions::Left] {
+                    if self.get(&point.translate_in_direction(&side, 1)) != Some(name) {
                         // Other region (or boundary). Add fence (and possibly merge other fences)
-                        let mut fence = Fence { side, points: vec![point] };
+                        let mut fence = Fence { side, points: vec![*point] };
 
                         fences.retain(|f| !fence.merge(f));
                         fences.push(fence);
-
-                        None
-                    }
-                }
-            }
-
-            // Got a new region.
-
-            let mut queue: VecDeque<Point> = VecDeque::new();
-            queue.push_back(point);
-
-            let mut fences = vec![];
-            let mut area = 0;
-
-            while let Some(current) = queue.pop_front() {
-                area += 1;
-
-                for side in vec![Directions::Top, Directions::Right, Directions::Bottom, Directions::Left] {
-                    if let Some(next_cell) = handle_side(self, current, side, name, &mut fences) {
-                        if handled_cells.insert(next_cell) {
-                            queue.push_back(next_cell);
-                        }
                     }
                 }
             }
@@ -88,8 +60,8 @@ impl Garden {
             let perimeter = fences.iter().map(|f| f.len()).sum();
             let sides = fences.len();
 
-            regions.push(Region { name, area, perimeter, sides });
-        }
+            Region { name, area: points.len(), perimeter, sides }
+        }).collect();
 
         regions.sort_by(|l, r| l.name.cmp(&r.name).then_with(|| l.area.cmp(&r.area)).then_with(|| l.perimeter.cmp(&r.perimeter)));
 
@@ -144,7 +116,7 @@ impl Fence {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day12::{Garden, Region};
+    use crate::days::y2024::day12::{Garden, Region};
 
     const SMALL_TEST_INPUT: &str = "\
         AAAA\n\
@@ -1,26 +1,28 @@
 use std::cmp::Ordering;
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::geometry::Point;
 use crate::util::parser::Parser;
 
-pub const DAY13: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day13;
 
-fn puzzle1(input: &String) {
-    let machines = parse_input(input).unwrap();
+impl Day for Day13 {
+    type Input<'a> = Vec<ClawMachine>;
 
-    let cost: usize = machines.iter().filter_map(|m| m.get_corrected_solve(0)).map(|s| s.cost()).sum();
-    println!("It takes {} coins to win all possible prizes.", cost);
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
 
-fn puzzle2(input: &String) {
-    let machines = parse_input(input).unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let cost: usize = input.iter().filter_map(|m| m.get_corrected_solve(0)).map(|s| s.cost()).sum();
+        Ok(cost.into())
+    }
 
-    let cost: usize = machines.iter().filter_map(|m| m.get_corrected_solve(10_000_000_000_000)).map(|s| s.cost()).sum();
-    println!("Oops! It takes {} coins to really win all possible prizes.", cost);
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let cost: usize = input.iter().filter_map(|m| m.get_corrected_solve(10_000_000_000_000)).map(|s| s.cost()).sum();
+        Ok(cost.into())
+    }
 }
 
 fn parse_input(input: &str) -> Result<Vec<ClawMachine>, String> {
@@ -28,7 +30,7 @@ fn parse_input(input: &str) -> Result<Vec<ClawMachine>, String> {
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-struct ClawMachine {
+pub struct ClawMachine {
     prize_loc: Point,
     button_a: Point,
     button_b: Point,
@@ -81,10 +83,56 @@ impl ClawMachine {
     }
 }
 
+impl FromStr for ClawMachine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        parser.literal("Button A:")?;
+        parser.literal("X+")?;
+        let button_a_x = parser.isize()?;
+        parser.literal(",")?;
+        parser.literal("Y+")?;
+        let button_a_y = parser.isize()?;
+
+        parser.literal("Button B:")?;
+        parser.literal("X+")?;
+        let button_b_x = parser.isize()?;
+        parser.literal(",")?;
+        parser.literal("Y+")?;
+        let button_b_y = parser.isize()?;
+
+        parser.literal("Prize:")?;
+        parser.literal("X=")?;
+        let prize_x = parser.isize()?;
+        parser.literal(",")?;
+        parser.literal("Y=")?;
+        let prize_y = parser.isize()?;
+
+        Ok(Self {
+            prize_loc: Point { x: prize_x, y: prize_y },
+            button_a: Point { x: button_a_x, y: button_a_y },
+            button_b: Point { x: button_b_x, y: button_b_y },
+        })
+    }
+}
+
+impl Ord for ClawMachineState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost().cmp(&other.cost()).reverse()
+    }
+}
+
+impl PartialOrd<Self> for ClawMachineState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp::Ordering;
-    use crate::days::day13::{parse_input, ClawMachine, ClawMachineState};
+    use crate::days::y2024::day13::{parse_input, ClawMachine, ClawMachineState};
 
     const TEST_INPUT: &str = "\
         Button A: X+94, Y+34
@@ -147,49 +195,3 @@ mod tests {
         assert_eq!(state_a.cmp(&state_d), Ordering::Equal);
     }
 }
-
-impl FromStr for ClawMachine {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parser = Parser::new(s);
-        parser.literal("Button A:")?;
-        parser.literal("X+")?;
-        let button_a_x = parser.isize()?;
-        parser.literal(",")?;
-        parser.literal("Y+")?;
-        let button_a_y = parser.isize()?;
-
-        parser.literal("Button B:")?;
-        parser.literal("X+")?;
-        let button_b_x = parser.isize()?;
-        parser.literal(",")?;
-        parser.literal("Y+")?;
-        let button_b_y = parser.isize()?;
-
-        parser.literal("Prize:")?;
-        parser.literal("X=")?;
-        let prize_x = parser.isize()?;
-        parser.literal(",")?;
-        parser.literal("Y=")?;
-        let prize_y = parser.isize()?;
-
-        Ok(Self {
-            prize_loc: Point { x: prize_x, y: prize_y },
-            button_a: Point { x: button_a_x, y: button_a_y },
-            button_b: Point { x: button_b_x, y: button_b_y },
-        })
-    }
-}
-
-impl Ord for ClawMachineState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.cost().cmp(&other.cost()).reverse()
-    }
-}
-
-impl PartialOrd<Self> for ClawMachineState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
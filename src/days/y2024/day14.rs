@@ -0,0 +1,214 @@
+use std::io::stdin;
+use std::str::FromStr;
+use crate::context::{Context, Frame, Sink, TerminalSink};
+use crate::days::{Answer, Day};
+use crate::util::collection::CollectionExtension;
+use crate::util::geometry::{Bounds, Grid, Point};
+use crate::util::image::{Color, Image};
+use crate::util::parser::Parser;
+
+pub struct Day14;
+
+impl Day for Day14 {
+    type Input<'a> = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(get_safety_factor(input, 100, WIDTH, HEIGHT).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        // "find a Christmas tree"
+        if ctx.is_interactive() {
+            return find_tree_interactively(input, ctx).map(|t| (t as usize).into());
+        }
+
+        let t = find_tree(input, WIDTH, HEIGHT);
+        match ctx.steps() {
+            // The robots are back where they started after a full cycle, so there is nothing beyond it to see.
+            Some(_) => {
+                for step in 0..WIDTH * HEIGHT {
+                    ctx.visualize_step(step as usize, format!("At {}", step), || Frame::Image(render_time(input, step, WIDTH, HEIGHT)));
+                }
+            }
+            None => ctx.visualize(format!("At {}", t), || Frame::Image(render_time(input, t, WIDTH, HEIGHT))),
+        }
+
+        Ok((t as usize).into())
+    }
+}
+
+const WIDTH: isize = 101;
+const HEIGHT: isize = 103;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Robot {
+    start: Point,
+    velocity_x: isize,
+    velocity_y: isize,
+}
+
+impl Robot {
+    fn position_after(&self, t: isize, width: isize, height: isize) -> Point {
+        let raw_x = self.start.x + (t * self.velocity_x);
+        let raw_y = self.start.y + (t * self.velocity_y);
+
+        // After mod, we can have a negative number, so we add the length again. However, for a positive number that needs another mod.
+        let wrapped_x = ((raw_x % width) + width) % width;
+        let wrapped_y = ((raw_y % height) + height) % height;
+
+        (wrapped_x, wrapped_y).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, String> {
+    input.lines().map(|l| l.parse()).collect()
+}
+
+fn get_safety_factor(robots: &[Robot], t: isize, width: isize, height: isize) -> usize {
+    let middle_width = width / 2;
+    let middle_height = height / 2;
+
+    let top_left = Bounds { top: 0, left: 0, width: middle_width as usize, height: middle_height as usize };
+    let top_right = Bounds { top: 0, left: middle_width + 1, width: middle_width as usize, height: middle_height as usize };
+    let bottom_left = Bounds { top: middle_height + 1, left: 0, width: middle_width as usize, height: middle_height as usize };
+    let bottom_right = Bounds { top: middle_height + 1, left: middle_width + 1, width: middle_width as usize, height: middle_height as usize };
+
+    let points_at_t: Vec<_> = robots.iter().map(|r| r.position_after(t, width, height)).collect();
+
+    let top_left_robots = points_at_t.iter().filter(|p| top_left.contains(p)).count();
+    let top_right_robots = points_at_t.iter().filter(|p| top_right.contains(p)).count();
+    let bottom_left_robots = points_at_t.iter().filter(|p| bottom_left.contains(p)).count();
+    let bottom_right_robots = points_at_t.iter().filter(|p| bottom_right.contains(p)).count();
+
+    top_left_robots * top_right_robots * bottom_left_robots * bottom_right_robots
+}
+
+/// When the robots draw the tree, most of them are packed together, so their positions are spread
+/// out the least. The x positions repeat every `width` steps and the y positions every `height`
+/// steps, so we find the least spread out time for both separately. As the width and height are
+/// coprime, there is just one time within the full cycle that matches both.
+fn find_tree(robots: &[Robot], width: isize, height: isize) -> isize {
+    let best_x = (0..width).min_by_key(|t| spread(robots.iter().map(|r| r.position_after(*t, width, height).x))).unwrap_or(0);
+    let best_y = (0..height).min_by_key(|t| spread(robots.iter().map(|r| r.position_after(*t, width, height).y))).unwrap_or(0);
+
+    (0..height).map(|k| best_x + k * width).find(|t| t % height == best_y).unwrap_or(best_x)
+}
+
+/// The variance of the values, times the count squared to keep it an integer.
+fn spread<I: Iterator<Item = isize>>(values: I) -> isize {
+    let (count, sum, sum_of_squares) = values.fold((0, 0, 0), |(count, sum, squares), v| (count + 1, sum + v, squares + v * v));
+    count * sum_of_squares - sum * sum
+}
+
+/// We simply try to find a t where positions are unique, and then prompt the user to confirm or
+/// deny the existence of a Christmas tree...
+fn find_tree_interactively(robots: &[Robot], ctx: &Context) -> Result<isize, String> {
+    let mut t = 1;
+    loop {
+        let points_at_t: Vec<_> = robots.iter().map(|r| r.position_after(t, WIDTH, HEIGHT)).collect();
+        let unique = points_at_t.deduplicate();
+        if unique.len() == points_at_t.len() {
+            // The frame is needed to answer the question, so it is shown even without a sink.
+            let label = format!("At {}", t);
+            let frame = || Frame::Image(render_time(robots, t, WIDTH, HEIGHT));
+            if ctx.is_visualizing() {
+                ctx.visualize(label, frame);
+            } else {
+                TerminalSink.frame(&label, &frame());
+            }
+
+            eprintln!("Is there a tree? [Y/n]");
+            let mut answer = String::new();
+            stdin().read_line(&mut answer).map_err(|e| e.to_string())?;
+
+            match answer.trim() {
+                "" | "Y" | "y" => return Ok(t),
+                _ => {}
+            }
+        }
+
+        t += 1;
+    }
+}
+
+fn render_time(robots: &[Robot], t: isize, width: isize, height: isize) -> Image {
+    let mut grid = Grid::with_size(Bounds::from_size(width as usize, height as usize));
+    for robot in robots {
+        grid.set(robot.position_after(t, width, height), true);
+    }
+
+    grid.to_image(|robot| if *robot { Color::WHITE } else { Color::BLACK }, Color::BLACK)
+}
+
+impl FromStr for Robot {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        parser.literal("p=")?;
+        let start_x = parser.isize()?;
+        parser.literal(",")?;
+        let start_y = parser.isize()?;
+        parser.literal("v=")?;
+        let velocity_x = parser.isize()?;
+        parser.literal(",")?;
+        let velocity_y = parser.isize()?;
+
+        Ok(Self { start: (start_x, start_y).into(), velocity_x, velocity_y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2024::day14::{find_tree, get_safety_factor, parse_input, Robot};
+
+    const TEST_INPUT: &str = "\
+        p=0,4 v=3,-3\n\
+        p=6,3 v=-1,-3\n\
+        p=10,3 v=-1,2\n\
+        p=2,0 v=2,-1\n\
+        p=0,0 v=1,3\n\
+        p=3,0 v=-2,-2\n\
+        p=7,6 v=-1,-3\n\
+        p=3,0 v=-1,-2\n\
+        p=9,3 v=2,3\n\
+        p=7,3 v=-1,2\n\
+        p=2,4 v=2,-3\n\
+        p=9,5 v=-3,-3\n\
+    ";
+
+    #[test]
+    fn test_position_after() {
+        let robot = Robot { start: (2,4).into(), velocity_x: 2, velocity_y: -3 };
+        assert_eq!(robot.position_after(0, 11, 7), (2,4).into());
+        assert_eq!(robot.position_after(1, 11, 7), (4,1).into());
+        assert_eq!(robot.position_after(2, 11, 7), (6,5).into());
+        assert_eq!(robot.position_after(3, 11, 7), (8,2).into());
+        assert_eq!(robot.position_after(4, 11, 7), (10,6).into());
+        assert_eq!(robot.position_after(5, 11, 7), (1,3).into());
+    }
+
+    #[test]
+    fn get_safety_factory() {
+        let robots = parse_input(TEST_INPUT).unwrap();
+        let result = get_safety_factor(&robots, 100, 11, 7);
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn test_find_tree() {
+        // Robots that gather in a small block at t = 1234, and are scattered at any other time.
+        let t = 1234;
+        let robots: Vec<_> = (0..50).map(|i| {
+            let (velocity_x, velocity_y) = (i % 9 + 1, -(i % 11) - 1);
+            let backwards = Robot { start: (40 + i % 7, 50 + i / 7).into(), velocity_x: -velocity_x, velocity_y: -velocity_y };
+            Robot { start: backwards.position_after(t, 101, 103), velocity_x, velocity_y }
+        }).collect();
+
+        assert_eq!(find_tree(&robots, 101, 103), t);
+    }
+}
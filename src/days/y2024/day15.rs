@@ -1,33 +1,42 @@
 use std::fmt::{Display, Formatter, Write};
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::{Context, Frame};
+use crate::days::{Answer, Day};
 use crate::util::geometry::{Directions, Grid, Point};
+use crate::util::image::Color;
+use crate::util::render::Renderer;
 
-pub const DAY15: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day15;
 
-fn puzzle1(input: &String) {
-    let (mut grid, moves) = parse_input(input).unwrap();
+impl Day for Day15 {
+    type Input<'a> = (Grid<Tile>, Vec<Move>);
 
-    execute_moves(&mut grid, &moves);
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
 
-    println!("Checksum of box GPS coords is: {}", get_gps_checksum(&grid));
-}
+    fn part1(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        let (grid, moves) = input;
+        let mut grid = grid.clone();
+
+        execute_moves(&mut grid, moves, ctx);
 
-fn puzzle2(input: &String) {
-    let (grid, moves) = parse_input(input).unwrap();
+        Ok(get_gps_checksum(&grid).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        let (grid, moves) = input;
 
-    let mut widened_grid = widen_map(&grid);
+        let mut widened_grid = widen_map(grid);
 
-    execute_moves(&mut widened_grid, &moves);
+        execute_moves(&mut widened_grid, moves, ctx);
 
-    println!("Checksum of box GPS coords is: {}", get_gps_checksum(&widened_grid));
+        Ok(get_gps_checksum(&widened_grid).into())
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
-enum Tile {
+pub enum Tile {
     #[default]
     Empty,
     Wall,
@@ -38,7 +47,7 @@ enum Tile {
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-enum Move {
+pub enum Move {
     Up,
     Right,
     Down,
@@ -84,17 +93,33 @@ fn widen_map(grid: &Grid<Tile>) -> Grid<Tile> {
     new_grid
 }
 
-fn execute_moves(grid: &mut Grid<Tile>, moves: &Vec<Move>) {
+fn execute_moves(grid: &mut Grid<Tile>, moves: &[Move], ctx: &Context) {
     // Take the robot tile, and move if possible according to the list of moves.
     // Boxes can be pushed, as long as there is an empty tile behind them.
 
-    let mut robot_pos = grid.entries().iter().find(|(_, t)| Tile::Robot.eq(t)).map(|(p, _)| *p).unwrap(); // Just panic if the input is wrong.
+    let mut robot_pos = grid.find(|t| Tile::Robot.eq(t)).unwrap(); // Just panic if the input is wrong.
+    let renderer = renderer();
+    ctx.visualize_step(0, "Move 0", || Frame::Image(renderer.render(grid, &[])));
 
-    for mov in moves {
+    for (step, mov) in moves.iter().enumerate() {
         if can_move(grid, &robot_pos, mov) {
-            robot_pos = do_move(grid, &robot_pos, mov, 0);
+            robot_pos = do_move(grid, &robot_pos, mov);
         }
+        ctx.visualize_step(step + 1, format!("Move {}", step + 1), || Frame::Image(renderer.render(grid, &[])));
     }
+
+    if ctx.steps().is_none() {
+        ctx.visualize("Done", || Frame::Image(renderer.render(grid, &[])));
+    }
+}
+
+fn renderer() -> Renderer<Tile> {
+    Renderer::new(|tile| match tile {
+        Tile::Empty => Color::BLACK,
+        Tile::Wall => Color::gray(128),
+        Tile::Box | Tile::BoxLeft | Tile::BoxRight => Color::rgb(200, 140, 60),
+        Tile::Robot => Color::RED,
+    })
 }
 
 fn can_move(grid: &Grid<Tile>, pos: &Point, mov: &Move) -> bool {
@@ -150,7 +175,7 @@ fn can_move(grid: &Grid<Tile>, pos: &Point, mov: &Move) -> bool {
     }
 }
 
-fn do_move(grid: &mut Grid<Tile>, pos: &Point, mov: &Move, safety: usize) -> Point {
+fn do_move(grid: &mut Grid<Tile>, pos: &Point, mov: &Move) -> Point {
     let tile = match grid.get(pos) {
         Some(t @ (Tile::Empty | Tile::Box | Tile::Robot | Tile::BoxRight | Tile::BoxLeft)) => t,
         v => panic!("Can't move tile {:?}", v)
@@ -161,32 +186,32 @@ fn do_move(grid: &mut Grid<Tile>, pos: &Point, mov: &Move, safety: usize) -> Poi
     match tile {
         Tile::Empty => { /* nothing to do */ },
         t @ (Tile::Box | Tile::Robot) => {
-            do_move(grid, &new_pos, mov, safety + 1);
+            do_move(grid, &new_pos, mov);
             grid.set(new_pos, t);
             grid.set(*pos, Tile::Empty);
         },
         Tile::BoxLeft => {
-            do_move(grid, &new_pos, mov, safety + 1);
+            do_move(grid, &new_pos, mov);
             grid.set(new_pos, Tile::BoxLeft);
             grid.set(*pos, Tile::Empty);
 
             // Note: only when moving up or down we need to explicitly move the other half along.
             if Move::Up.eq(mov) || Move::Down.eq(mov) {
                 let right_pos = new_pos.translate_in_direction(&Directions::Right, 1);
-                do_move(grid, &right_pos, mov, safety + 1);
+                do_move(grid, &right_pos, mov);
                 grid.set(right_pos, Tile::BoxRight);
                 grid.set(pos.translate_in_direction(&Directions::Right, 1), Tile::Empty);
             }
         },
         Tile::BoxRight => {
-            do_move(grid, &new_pos, mov, safety + 1);
+            do_move(grid, &new_pos, mov);
             grid.set(new_pos, Tile::BoxRight);
             grid.set(*pos, Tile::Empty);
 
             // Note: only when moving up or down we need to explicitly move the other half along.
             if Move::Up.eq(mov) || Move::Down.eq(mov) {
                 let left_pos = new_pos.translate_in_direction(&Directions::Left, 1);
-                do_move(grid, &left_pos, mov, safety + 1);
+                do_move(grid, &left_pos, mov);
                 grid.set(left_pos, Tile::BoxLeft);
                 grid.set(pos.translate_in_direction(&Directions::Left, 1), Tile::Empty);
             }
@@ -230,9 +255,51 @@ impl Move {
     }
 }
 
+impl FromStr for Tile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Tile::Empty),
+            "#" => Ok(Tile::Wall),
+            "O" => Ok(Tile::Box),
+            "@" => Ok(Tile::Robot),
+            _ => Err(format!("Unknown tile: {}", s)),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Empty => f.write_char('.'),
+            Tile::Wall => f.write_char('#'),
+            Tile::Box => f.write_char('O'),
+            Tile::Robot => f.write_char('@'),
+            Tile::BoxLeft => f.write_char('['),
+            Tile::BoxRight => f.write_char(']'),
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "^" => Ok(Move::Up),
+            ">" => Ok(Move::Right),
+            "v" => Ok(Move::Down),
+            "<" => Ok(Move::Left),
+            _ => Err(format!("Unknown move: {}", s)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day15::{execute_moves, get_gps_checksum, parse_input, widen_map};
+    use crate::context::Context;
+    use crate::days::y2024::day15::{execute_moves, get_gps_checksum, parse_input, widen_map};
 
     const SMALL_INPUT: &str = "\
         ########\n\
@@ -287,7 +354,7 @@ mod tests {
     fn test_small_execute_moves() {
         let (mut grid, moves) = parse_input(SMALL_INPUT).unwrap();
 
-        execute_moves(&mut grid, &moves);
+        execute_moves(&mut grid, &moves, &Context::default());
 
         assert_eq!(format!("{}", grid), "\
             ########\n\
@@ -305,7 +372,7 @@ mod tests {
     fn test_execute_moves() {
         let (mut grid, moves) = parse_input(TEST_INPUT).unwrap();
 
-        execute_moves(&mut grid, &moves);
+        execute_moves(&mut grid, &moves, &Context::default());
 
         assert_eq!(format!("{}", grid), "\
             ##########\n\
@@ -324,11 +391,11 @@ mod tests {
     #[test]
     fn test_get_gps_checksum() {
         let (mut small_grid, small_moves) = parse_input(SMALL_INPUT).unwrap();
-        execute_moves(&mut small_grid, &small_moves);
+        execute_moves(&mut small_grid, &small_moves, &Context::default());
         assert_eq!(get_gps_checksum(&small_grid), 2028);
 
         let (mut large_grid, large_moves) = parse_input(TEST_INPUT).unwrap();
-        execute_moves(&mut large_grid, &large_moves);
+        execute_moves(&mut large_grid, &large_moves, &Context::default());
         assert_eq!(get_gps_checksum(&large_grid), 10092);
     }
 
@@ -354,7 +421,7 @@ mod tests {
         let (small_grid, moves) = parse_input(SMALL_WIDEN_TEST).unwrap();
 
         let mut small_wider_grid = widen_map(&small_grid);
-        execute_moves(&mut small_wider_grid, &moves);
+        execute_moves(&mut small_wider_grid, &moves, &Context::default());
 
         assert_eq!(format!("{}", small_wider_grid), "\
             ##############\n\
@@ -369,7 +436,7 @@ mod tests {
         let (large_grid, moves) = parse_input(TEST_INPUT).unwrap();
 
         let mut large_wider_grid = widen_map(&large_grid);
-        execute_moves(&mut large_wider_grid, &moves);
+        execute_moves(&mut large_wider_grid, &moves, &Context::default());
 
         assert_eq!(format!("{}", large_wider_grid), "\
             ####################\n\
@@ -389,48 +456,7 @@ mod tests {
     fn test_widened_gps() {
         let (large_grid, large_moves) = parse_input(TEST_INPUT).unwrap();
         let mut widened_grid = widen_map(&large_grid);
-        execute_moves(&mut widened_grid, &large_moves);
+        execute_moves(&mut widened_grid, &large_moves, &Context::default());
         assert_eq!(get_gps_checksum(&widened_grid), 9021);
     }
-}
-
-impl FromStr for Tile {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Tile::Empty),
-            "#" => Ok(Tile::Wall),
-            "O" => Ok(Tile::Box),
-            "@" => Ok(Tile::Robot),
-            _ => Err(format!("Unknown tile: {}", s)),
-        }
-    }
-}
-
-impl Display for Tile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Tile::Empty => f.write_char('.'),
-            Tile::Wall => f.write_char('#'),
-            Tile::Box => f.write_char('O'),
-            Tile::Robot => f.write_char('@'),
-            Tile::BoxLeft => f.write_char('['),
-            Tile::BoxRight => f.write_char(']'),
-        }
-    }
-}
-
-impl FromStr for Move {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "^" => Ok(Move::Up),
-            ">" => Ok(Move::Right),
-            "v" => Ok(Move::Down),
-            "<" => Ok(Move::Left),
-            _ => Err(format!("Unknown move: {}", s)),
-        }
-    }
 }
\ No newline at end of file
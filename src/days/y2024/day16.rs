@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use crate::context::{Context, Frame};
+use crate::days::{Answer, Day};
+use crate::util::geometry::{DenseGrid, Directions, Point};
+use crate::util::image::{Color, Image};
+use crate::util::render::{Overlay, Renderer};
+use crate::util::search::{dijkstra, Search};
+
+pub struct Day16;
+
+impl Day for Day16 {
+    type Input<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        input.solve().map(Answer::from).ok_or("Maze has no path to the end".to_string())
+    }
+
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        let best_tiles = input.get_best_tiles().ok_or("Maze has no path to the end".to_string())?;
+        ctx.visualize("Best paths", || Frame::Image(render_best_tiles(input, &best_tiles)));
+
+        Ok(best_tiles.len().into())
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Wall,
+    Start,
+    End
+}
+
+type Maze = DenseGrid<Tile>;
+
+impl Maze {
+    fn get_left_direction(directions: Directions) -> Directions {
+        match directions {
+            Directions::Top => Directions::Left,
+            Directions::Right => Directions::Top,
+            Directions::Bottom => Directions::Right,
+            Directions::Left => Directions::Bottom,
+            _ => unreachable!()
+        }
+    }
+
+    fn get_right_direction(directions: Directions) -> Directions {
+        match directions {
+            Directions::Top => Directions::Right,
+            Directions::Right => Directions::Bottom,
+            Directions::Bottom => Directions::Left,
+            Directions::Left => Directions::Top,
+            _ => unreachable!()
+        }
+    }
+
+    fn solve(&self) -> Option<usize> {
+        self.search()?.goal_distance()
+    }
+
+    fn get_best_tiles(&self) -> Option<HashSet<Point>> {
+        let search = self.search()?;
+        Some(search.states_on_paths_to(search.goals()).into_iter().map(|(tile, _)| tile).collect())
+    }
+
+    fn search(&self) -> Option<Search<(Point, Directions)>> {
+        // Rotating clock/counter-clockwise would cost 1000 points
+        // Moving forward costs 1 point.
+        // The reindeer can face any direction on a tile, so the state of the search is both.
+        let start_pos = self.find(|t| Tile::Start.eq(t))?;
+        let end_pos = self.find(|t| Tile::End.eq(t))?;
+
+        let successors = |&(tile, direction): &(Point, Directions)| {
+            // Turning is only worth it when moving on right after, so turn and move in one go.
+            [(direction, 1), (Self::get_left_direction(direction), 1001), (Self::get_right_direction(direction), 1001)].into_iter()
+                .map(move |(direction, points)| ((tile.translate_in_direction(&direction, 1), direction), points))
+                .filter(move |((next, _), _)| matches!(self.get_ref(next), Some(Tile::Empty | Tile::End)))
+        };
+
+        let search = dijkstra((start_pos, Directions::Right), successors, |(tile, _)| *tile == end_pos);
+        search.goal_distance().map(|_| search)
+    }
+}
+
+impl FromStr for Tile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Tile::Empty),
+            "#" => Ok(Tile::Wall),
+            "S" => Ok(Tile::Start),
+            "E" => Ok(Tile::End),
+            _ => Err(format!("Unknown tile: {}", s))
+        }
+    }
+}
+
+fn render_best_tiles(maze: &Maze, best_tiles: &HashSet<Point>) -> Image {
+    let renderer = Renderer::new(|tile| match tile {
+        Tile::Empty => Color::BLACK,
+        Tile::Wall => Color::gray(128),
+        Tile::Start => Color::BLUE,
+        Tile::End => Color::RED,
+    });
+
+    // Keep the start and end in sight, the paths run over them.
+    let path = best_tiles.iter().copied().filter(|p| maze.get(p) == Some(Tile::Empty));
+    renderer.render(maze, &[Overlay::new(path, Color::GREEN)])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2024::day16::Maze;
+
+    const EXAMPLE_MAZE_A: &str = "\
+        ###############\n\
+        #.......#....E#\n\
+        #.#.###.#.###.#\n\
+        #.....#.#...#.#\n\
+        #.###.#####.#.#\n\
+        #.#.#.......#.#\n\
+        #.#.#####.###.#\n\
+        #...........#.#\n\
+        ###.#.#####.#.#\n\
+        #...#.....#.#.#\n\
+        #.#.#.###.#.#.#\n\
+        #.....#...#.#.#\n\
+        #.###.#.#.#.#.#\n\
+        #S..#.....#...#\n\
+        ###############\n\
+    ";
+
+    const EXAMPLE_MAZE_B: &str = "\
+        #################\n\
+        #...#...#...#..E#\n\
+        #.#.#.#.#.#.#.#.#\n\
+        #.#.#.#...#...#.#\n\
+        #.#.#.#.###.#.#.#\n\
+        #...#.#.#.....#.#\n\
+        #.#.#.#.#.#####.#\n\
+        #.#...#.#.#.....#\n\
+        #.#.#####.#.###.#\n\
+        #.#.#.......#...#\n\
+        #.#.###.#####.###\n\
+        #.#.#...#.....#.#\n\
+        #.#.#.#####.###.#\n\
+        #.#.#.........#.#\n\
+        #.#.#.#########.#\n\
+        #S#.............#\n\
+        #################\n\
+    ";
+
+    #[test]
+    fn test_solve() {
+        let maze_a: Maze = EXAMPLE_MAZE_A.parse().unwrap();
+        let maze_b: Maze = EXAMPLE_MAZE_B.parse().unwrap();
+
+        assert_eq!(maze_a.solve(), Some(7036));
+        assert_eq!(maze_b.solve(), Some(11048));
+    }
+
+    #[test]
+    fn test_get_best_tiles() {
+        let maze_a: Maze = EXAMPLE_MAZE_A.parse().unwrap();
+        let maze_b: Maze = EXAMPLE_MAZE_B.parse().unwrap();
+
+        assert_eq!(maze_a.get_best_tiles().map(|t| t.len()), Some(45));
+        assert_eq!(maze_b.get_best_tiles().map(|t| t.len()), Some(64));
+    }
+}
@@ -1,30 +1,31 @@
 use std::collections::VecDeque;
 use std::ops::BitXor;
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::collection::CollectionExtension;
 use crate::util::parser::Parser;
 
-pub const DAY17: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day17;
 
-fn puzzle1(input: &String) {
-    let program: Program = input.parse().unwrap();
+impl Day for Day17 {
+    type Input<'a> = Program;
 
-    let output = program.run();
-    println!("Program output: {}", output.map(|v| v.to_string()).join(","));
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
 
-fn puzzle2(input: &String) {
-    let program: Program = input.parse().unwrap();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let output = input.run();
+        Ok(output.map(|v| v.to_string()).join(",").into())
+    }
 
-    let output = program.find_quine_value();
-    println!("Reg A value for quine: {}", output.unwrap());
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        input.find_quine_value(ctx).map(Answer::from).ok_or("Could not find a value for reg A that makes a quine".to_string())
+    }
 }
 
-struct Program {
+pub struct Program {
     reg_a: usize,
     reg_b: usize,
     reg_c: usize,
@@ -60,7 +61,7 @@ impl Program {
             match operation {
                 0 => {
                     // adv, divide value in reg_a by 2^combo
-                    reg_a = reg_a / 2_usize.pow(combo_operand.unwrap() as u32);
+                    reg_a /= 2_usize.pow(combo_operand.unwrap() as u32);
                 },
                 1 => {
                     // bxl, bitwise XOR of reg_b and the literal operand
@@ -102,7 +103,7 @@ impl Program {
         output
     }
 
-    fn find_quine_value(&self) -> Option<usize> {
+    fn find_quine_value(&self, ctx: &Context) -> Option<usize> {
         // Base on my input, which divides reg_a by 8 every loop, we first check which value(s)
         // between 1..8 output a single digit matching the last program value.
         // Taking that number, we multiply by 8**(program length), and validate output
@@ -117,7 +118,7 @@ impl Program {
             }
         }).unwrap();
 
-        println!("Program divides a by {} every loop", divisor);
+        ctx.debug(|| format!("Program divides a by {} every loop", divisor));
 
         // To end up at 0 in the last, run, the initial a value cannot be more than the a_divisor,
         // it obviously also cannot be 0.
@@ -150,9 +151,36 @@ impl Program {
     }
 }
 
+impl FromStr for Program {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+
+        parser.literal("Register A:")?;
+        let reg_a = parser.usize()?;
+        parser.literal("Register B:")?;
+        let reg_b = parser.usize()?;
+        parser.literal("Register C:")?;
+        let reg_c = parser.usize()?;
+
+        parser.literal("Program:")?;
+        let mut program = vec![];
+        program.push(parser.usize()?);
+
+        while !parser.is_exhausted() {
+            parser.literal(",")?;
+            program.push(parser.usize()?);
+        }
+
+        Ok(Self { reg_a, reg_b, reg_c, program })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day17::Program;
+    use crate::context::Context;
+    use crate::days::y2024::day17::Program;
 
     const TEST_INPUT: &str = "\
         Register A: 729\n\
@@ -183,33 +211,7 @@ mod tests {
     fn test_find_quine_value() {
         let quine: Program = QUINE_INPUT.parse().unwrap();
 
-        let quine_value = quine.find_quine_value();
+        let quine_value = quine.find_quine_value(&Context::default());
         assert_eq!(quine_value, Some(117440));
     }
-}
-
-impl FromStr for Program {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parser = Parser::new(s);
-
-        parser.literal("Register A:")?;
-        let reg_a = parser.usize()?;
-        parser.literal("Register B:")?;
-        let reg_b = parser.usize()?;
-        parser.literal("Register C:")?;
-        let reg_c = parser.usize()?;
-
-        parser.literal("Program:")?;
-        let mut program = vec![];
-        program.push(parser.usize()?);
-
-        while !parser.is_exhausted() {
-            parser.literal(",")?;
-            program.push(parser.usize()?);
-        }
-
-        Ok(Self { reg_a, reg_b, reg_c, program })
-    }
 }
\ No newline at end of file
@@ -0,0 +1,221 @@
+use std::fmt::{Display, Formatter, Write};
+use crate::context::{Context, Frame};
+use crate::days::{Answer, Day};
+use crate::util::disjoint_set::first_blocking;
+use crate::util::geometry::{Bounds, DenseGrid, Point};
+use crate::util::image::{Color, Image};
+use crate::util::render::{Overlay, Renderer};
+use crate::util::search::astar_grid;
+
+pub struct Day18;
+
+impl Day for Day18 {
+    type Input<'a> = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        // Shorter inputs, like the example, simply drop all of their bytes.
+        let dropped = input.len().min(1024);
+        let mut grid = DenseGrid::with_size(Bounds::from_size(71, 71));
+        drop_bytes(&mut grid, &input[..dropped])?;
+        let path = shortest_path_to_exit(&grid).ok_or(format!("No path to the exit after dropping {} bytes", dropped))?;
+        ctx.visualize("Path", || Frame::Image(render(&grid, &path, None)));
+
+        Ok(path.len().into())
+    }
+
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        let dropped = input.len().min(1024);
+        let mut grid = DenseGrid::with_size(Bounds::from_size(71, 71));
+        drop_bytes(&mut grid, &input[..dropped])?;
+        check_in_bounds(grid.bounds, &input[dropped..])?;
+
+        let blocker = find_byte_blocking_path(&mut grid, &input[dropped..], ctx).ok_or("None of the bytes blocked the path".to_string())?;
+        Ok(format!("{},{}", blocker.x, blocker.y).into())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>, String> {
+    input.lines().map(|l| l.parse()).collect()
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+enum State {
+    #[default]
+    Free,
+    Corrupted
+}
+
+fn drop_bytes(grid: &mut DenseGrid<State>, bytes: &[Point]) -> Result<(), String> {
+    check_in_bounds(grid.bounds, bytes)?;
+    for point in bytes {
+        grid.set(*point, State::Corrupted);
+    }
+
+    Ok(())
+}
+
+fn check_in_bounds(bounds: Bounds, bytes: &[Point]) -> Result<(), String> {
+    match bytes.iter().find(|p| !bounds.contains(p)) {
+        Some(byte) => Err(format!("Byte {},{} falls outside of the memory space", byte.x, byte.y)),
+        None => Ok(()),
+    }
+}
+
+fn shortest_path_to_exit(grid: &DenseGrid<State>) -> Option<Vec<Point>> {
+    let start = grid.bounds.top_left();
+    let end = grid.bounds.bottom_right();
+
+    // The steps taken, so leave out where we started.
+    let mut path = astar_grid(grid, start, end, |s| State::Free.eq(s)).path()?;
+    path.remove(0);
+    Some(path)
+}
+
+fn find_byte_blocking_path(grid: &mut DenseGrid<State>, bytes: &[Point], ctx: &Context) -> Option<Point> {
+    let passable = |p: &Point| grid.get_ref(p) == Some(&State::Free);
+    let blocking = first_blocking(grid.bounds, passable, bytes, grid.bounds.top_left(), grid.bounds.bottom_right())?;
+
+    if ctx.is_visualizing() {
+        show_bytes_dropping(grid, &bytes[..=blocking], ctx);
+    }
+
+    Some(bytes[blocking])
+}
+
+/// Drops the bytes one by one, and finds a new path whenever one falls on it. The last byte blocks the path.
+fn show_bytes_dropping(grid: &mut DenseGrid<State>, bytes: &[Point], ctx: &Context) {
+    let Some(mut path) = shortest_path_to_exit(grid) else { return };
+    let dropped = grid.values().iter().filter(|s| State::Corrupted.eq(s)).count();
+
+    for (i, byte) in bytes.iter().enumerate() {
+        grid.set(*byte, State::Corrupted);
+        if path.contains(byte) {
+            if let Some(new_path) = shortest_path_to_exit(grid) {
+                path = new_path;
+            }
+        }
+
+        let step = dropped + i + 1;
+        let frame = || Frame::Image(render(grid, &path, Some(*byte)));
+        ctx.visualize_step(step, format!("Byte {}", step), frame);
+        if i == bytes.len() - 1 && ctx.steps().is_none() {
+            ctx.visualize(format!("Blocked by byte {}", step), frame);
+        }
+    }
+}
+
+/// Draws the memory space with the path through it, and the byte that fell last.
+fn render(grid: &DenseGrid<State>, path: &[Point], last_byte: Option<Point>) -> Image {
+    let renderer = Renderer::new(|state| match state {
+        State::Free => Color::BLACK,
+        State::Corrupted => Color::gray(128),
+    });
+
+    renderer.render(grid, &[Overlay::new(path.iter().copied(), Color::GREEN), Overlay::new(last_byte, Color::RED)])
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            State::Free => f.write_char('.'),
+            State::Corrupted => f.write_char('#'),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+    use crate::days::{Answer, Day};
+    use crate::days::y2024::day18::{drop_bytes, Day18, find_byte_blocking_path, parse_input, shortest_path_to_exit};
+    use crate::util::geometry::{Bounds, DenseGrid};
+
+    #[test]
+    fn test_drop_bytes() {
+        let bytes = parse_input(TEST_INPUT).unwrap();
+        let mut grid = DenseGrid::with_size(Bounds::from_size(7, 7));
+        drop_bytes(&mut grid, &bytes[..12]).unwrap();
+
+        assert_eq!(format!("{}", grid), "\
+            ...#...\n\
+            ..#..#.\n\
+            ....#..\n\
+            ...#..#\n\
+            ..#..#.\n\
+            .#..#..\n\
+            #.#....\
+        ");
+    }
+
+    #[test]
+    fn test_shortest_path_to_exit() {
+        let bytes = parse_input(TEST_INPUT).unwrap();
+        let mut grid = DenseGrid::with_size(Bounds::from_size(7, 7));
+        drop_bytes(&mut grid, &bytes[..12]).unwrap();
+
+        let path = shortest_path_to_exit(&grid).unwrap();
+
+        assert_eq!(path.len(), 22);
+    }
+
+    #[test]
+    fn test_find_byte_blocking_path() {
+        let bytes = parse_input(TEST_INPUT).unwrap();
+        let mut grid = DenseGrid::with_size(Bounds::from_size(7, 7));
+        drop_bytes(&mut grid, &bytes[..12]).unwrap();
+
+        let result = find_byte_blocking_path(&mut grid, &bytes[12..], &Context::default());
+        assert_eq!(result, Some((6,1).into()));
+    }
+
+    #[test]
+    fn test_short_input() {
+        // The example has fewer than 1024 bytes, which are all dropped on the full size memory space.
+        let bytes = Day18::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(Day18::part1(&bytes, &Context::default()), Ok(Answer::Number(146)));
+        assert_eq!(Day18::part2(&bytes, &Context::default()), Err("None of the bytes blocked the path".to_string()));
+    }
+
+    #[test]
+    fn test_bytes_outside_memory_space() {
+        let error = Err("Byte 71,3 falls outside of the memory space".to_string());
+        assert_eq!(Day18::part1(&vec![(1, 2).into(), (71, 3).into()], &Context::default()), error);
+
+        let mut bytes = vec![(0, 0).into(); 1024];
+        bytes.push((71, 3).into());
+        assert_eq!(Day18::part2(&bytes, &Context::default()), error);
+    }
+
+    const TEST_INPUT: &str = "\
+        5,4\n\
+        4,2\n\
+        4,5\n\
+        3,0\n\
+        2,1\n\
+        6,3\n\
+        2,4\n\
+        1,5\n\
+        0,6\n\
+        3,3\n\
+        2,6\n\
+        5,1\n\
+        1,2\n\
+        5,5\n\
+        2,5\n\
+        6,5\n\
+        1,4\n\
+        0,4\n\
+        6,4\n\
+        1,1\n\
+        6,1\n\
+        1,0\n\
+        0,5\n\
+        1,6\n\
+        2,0\n\
+    ";
+}
\ No newline at end of file
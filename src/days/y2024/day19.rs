@@ -1,26 +1,27 @@
 use std::collections::HashMap;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 
-pub const DAY19: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day19;
 
-fn puzzle1(input: &String) {
-    let towels: Towels = input.as_str().into();
+impl Day for Day19 {
+    type Input<'a> = Towels<'a>;
 
-    let possible_designs = towels.get_possible_designs();
-    println!("We can creates {} of the designs.", possible_designs.len());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.try_into()
+    }
 
-fn puzzle2(input: &String) {
-    let towels: Towels = input.as_str().into();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_possible_designs().len().into())
+    }
 
-    println!("The possible designs can be stacked in {} different ways.", towels.get_possible_design_arrangements());
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(input.get_possible_design_arrangements().into())
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
-struct Towels<'a> {
+pub struct Towels<'a> {
     available_patterns: Vec<&'a str>,
     designs: Vec<&'a str>,
 }
@@ -62,9 +63,33 @@ impl <'a> Towels<'_> {
     }
 }
 
+impl <'a> TryFrom<&'a str> for Towels<'a> {
+    type Error = String;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut lines = s.lines();
+
+        // First line is a comma-separated list of available towel patterns
+        let patterns_line = lines.next().ok_or("Expected a line of towel patterns".to_string())?;
+        let available_patterns: Vec<_> = patterns_line.split(",").map(|p| p.trim()).collect();
+        if available_patterns.iter().any(|p| p.is_empty()) {
+            return Err(format!("Empty towel pattern in '{}'", patterns_line));
+        }
+
+        // Then an empty line, the third line and on are designs:
+        match lines.next() {
+            Some(line) if line.trim().is_empty() => {},
+            _ => return Err("Expected an empty line between the towel patterns and the designs".to_string()),
+        }
+        let designs = lines.map(|l| l.trim()).filter(|l| !l.is_empty()).collect();
+
+        Ok(Self { available_patterns, designs })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day19::Towels;
+    use crate::days::y2024::day19::Towels;
 
     const TEST_INPUT: &str = "\
         r, wr, b, g, bwu, rb, gb, br\n\
@@ -79,9 +104,17 @@ mod tests {
         bbrgwb\n\
     ";
 
+    #[test]
+    fn test_parse_towels() {
+        assert!(Towels::try_from("").is_err());
+        assert!(Towels::try_from("r, wr").is_err());
+        assert!(Towels::try_from("r, , b\n\nbrwrr").is_err());
+        assert_eq!(Towels::try_from("r, wr\n\nrwr\n"), Ok(Towels { available_patterns: vec!["r", "wr"], designs: vec!["rwr"] }));
+    }
+
     #[test]
     fn test_get_possible_designs() {
-        let towels: Towels = TEST_INPUT.into();
+        let towels: Towels = TEST_INPUT.try_into().unwrap();
 
         assert_eq!(towels.get_possible_designs(), vec![
             "brwrr",
@@ -95,22 +128,8 @@ mod tests {
 
     #[test]
     fn test_get_possible_design_arrangements() {
-        let towels: Towels = TEST_INPUT.into();
+        let towels: Towels = TEST_INPUT.try_into().unwrap();
 
         assert_eq!(towels.get_possible_design_arrangements(), 16);
     }
-}
-
-impl <'a> From<&'a str> for Towels<'a> {
-    fn from(s: &'a str) -> Self {
-        let lines: Vec<_> = s.lines().collect();
-
-        // First line is a comma-separated list of available towel patterns
-        let available_patterns = lines[0].split(",").map(|p| p.trim()).collect();
-
-        // Third line and on are designs:
-        let designs = lines[2..].into_iter().map(|l| l.trim()).filter(|l| !l.is_empty()).collect();
-
-        Self { available_patterns, designs }
-    }
 }
\ No newline at end of file
@@ -1,31 +1,36 @@
 use std::collections::HashMap;
 use std::str::FromStr;
-use crate::days::Day;
-use crate::util::geometry::{Directions, Grid, Point};
+use crate::context::Context;
+use crate::days::{Answer, Day};
+use crate::util::geometry::{DenseGrid, Point};
+use crate::util::search::bfs_grid;
 
-pub const DAY20: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day20;
 
-fn puzzle1(input: &String) {
-    let maze: Maze = input.parse().unwrap();
-    let cheats =  maze.find_cheats(2);
+impl Day for Day20 {
+    type Input<'a> = Maze;
 
-    let good_cheats = cheats.iter().filter(|c| c.time_saved >= 100).count();
-    println!("There are {} cheats that save more than 100ps", good_cheats);
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let cheats = input.find_cheats(2);
+
+        let good_cheats = cheats.iter().filter(|c| c.time_saved >= 100).count();
+        Ok(good_cheats.into())
+    }
 
-fn puzzle2(input: &String) {
-    let maze: Maze = input.parse().unwrap();
-    let cheats =  maze.find_cheats(20);
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let cheats = input.find_cheats(20);
 
-    let better_cheats = cheats.iter().filter(|c| c.time_saved >= 100).count();
-    println!("There are {} better cheats that save more than 100ps", better_cheats);
+        let better_cheats = cheats.iter().filter(|c| c.time_saved >= 100).count();
+        Ok(better_cheats.into())
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Default, Debug)]
-enum Tile {
+pub enum Tile {
     #[default]
     Empty,
     Start,
@@ -33,7 +38,7 @@ enum Tile {
     Wall
 }
 
-type Maze = Grid<Tile>;
+type Maze = DenseGrid<Tile>;
 
 struct Cheat {
     time_saved: usize,
@@ -48,25 +53,11 @@ impl Maze {
         //   - Check if N+2 is smaller than M
         //   - If so, result in a cheat with time_saved = M - N+2
 
-        let mut distance_map = HashMap::new();
-        let start_point = self.entries().iter().find(|(_, t)| Tile::Start.eq(t)).map(|(p, _)| *p).unwrap();
-        let end_point = self.entries().iter().find(|(_, t)| Tile::End.eq(t)).map(|(p, _)| *p).unwrap();
-
-        // loop 1, fill distance_map
-        let mut current = start_point;
-        let mut distance = 0;
-        let mut path = vec![];
-
-        loop {
-            path.push(current);
-            distance_map.insert(current, distance);
+        let start_point = self.find(|t| Tile::Start.eq(t)).unwrap();
 
-            if current == end_point { break; }
-
-            // There should always be a single point around that is Empty (or the End) and not visited, since there is just one path :shrug:
-            distance += 1;
-            current = current.get_points_around(Directions::NonDiagonal).iter().find(|p| !distance_map.contains_key(p) && (self.get(p) == Some(Tile::Empty) || self.get(p) == Some(Tile::End))).copied().unwrap();
-        }
+        // loop 1, fill distance_map. There is just one path, so everything reachable is on it.
+        let search = bfs_grid(self, start_point, |t| !Tile::Wall.eq(t), |_| false);
+        let distance_map = search.distances();
 
         // loop 2, check for cheats
         fn get_cheats_around(point: &Point, max_cheat_length: usize, distances: &HashMap<Point, usize>) -> Vec<Cheat> {
@@ -91,14 +82,28 @@ impl Maze {
             }).collect()
         }
 
-        path.iter().flat_map(|p| get_cheats_around(p, max_cheat_length, &distance_map)).collect()
+        distance_map.keys().flat_map(|p| get_cheats_around(p, max_cheat_length, distance_map)).collect()
+    }
+}
+
+impl FromStr for Tile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Self::Empty),
+            "#" => Ok(Self::Wall),
+            "S" => Ok(Self::Start),
+            "E" => Ok(Self::End),
+            _ => Err(format!("Unknown tile: {}", s))
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
-    use crate::days::day20::{Cheat, Maze};
+    use crate::days::y2024::day20::{Cheat, Maze};
 
     const TEST_INPUT: &str = "\
         ###############\n\
@@ -148,18 +153,4 @@ mod tests {
         let better_cheats = maze.find_cheats(20).iter().filter(|c| c.time_saved >= 50).count();
         assert_eq!(better_cheats, 285);
     }
-}
-
-impl FromStr for Tile {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Self::Empty),
-            "#" => Ok(Self::Wall),
-            "S" => Ok(Self::Start),
-            "E" => Ok(Self::End),
-            _ => Err(format!("Unknown tile: {}", s))
-        }
-    }
 }
\ No newline at end of file
@@ -1,27 +1,29 @@
 use std::collections::{HashMap, VecDeque};
 use std::fmt::{Display, Formatter, Write};
 use std::iter::Iterator;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::geometry::{Directions, Point};
 use crate::util::number::parse_usize;
 
-pub const DAY21: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day21;
 
-fn puzzle1(input: &String) {
-    let codes = input.lines().collect::<Vec<_>>();
+impl Day for Day21 {
+    type Input<'a> = Vec<&'a str>;
 
-    let total_complexity: usize = codes.iter().map(|c| get_code_cost(c, 2) * get_code_value(c)).sum();
-    println!("Total complexity (first member) = {}", total_complexity);
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        Ok(input.lines().collect())
+    }
 
-fn puzzle2(input: &String) {
-    let codes = input.lines().collect::<Vec<_>>();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let total_complexity: usize = input.iter().map(|c| get_code_cost(c, 2) * get_code_value(c)).sum();
+        Ok(total_complexity.into())
+    }
 
-    let total_complexity: usize = codes.iter().map(|c| get_code_cost(c, 25) * get_code_value(c)).sum();
-    println!("Total complexity (second member) = {}", total_complexity);
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let total_complexity: usize = input.iter().map(|c| get_code_cost(c, 25) * get_code_value(c)).sum();
+        Ok(total_complexity.into())
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
@@ -80,7 +82,7 @@ impl Moves {
         result
     }
 
-    fn get_best_cost(from_point: &Point, to_point: &Point, allowed_points: &Vec<Point>, cost_map: &CostMap) -> Option<usize> {
+    fn get_best_cost(from_point: &Point, to_point: &Point, allowed_points: &[Point], cost_map: &CostMap) -> Option<usize> {
         let up = if to_point.y < from_point.y { from_point.y - to_point.y } else { 0 };
         let right = if to_point.x > from_point.x { to_point.x - from_point.x } else { 0 };
         let left = if to_point.x < from_point.x { from_point.x - to_point.x } else { 0 };
@@ -95,7 +97,7 @@ impl Moves {
             // If the move moved onto empty space, skip it
             if !allowed_points.contains(&moves.point) { continue }
 
-            if let Some(cost) = moves.complete(&cost_map) {
+            if let Some(cost) = moves.complete(cost_map) {
                 match best_option {
                     Some(v) if v < cost => { continue }, // Current found best is better
                     _ => best_option = Some(cost),
@@ -103,7 +105,7 @@ impl Moves {
             }
 
             // Just. Try. Everything.
-            for mov in moves.get_next_options(&cost_map) {
+            for mov in moves.get_next_options(cost_map) {
                 queue.push_back(mov);
             }
         }
@@ -167,8 +169,8 @@ fn build_move_cost(number_of_controllers: usize) -> HashMap<(Move, Move), usize>
     for from in &all_moves {
         for to in &all_moves {
             // Iterate in pairs, getting the cost to move between them using the map.
-            let from_point = control_locations.get(&from).unwrap();
-            let to_point = control_locations.get(&to).unwrap();
+            let from_point = control_locations.get(from).unwrap();
+            let to_point = control_locations.get(to).unwrap();
 
             // If the first remove controller, the cost is 1 per move (+1 to activate), otherwise it should be found using the previous map.
             let cost = if number_of_controllers == 1 { from_point.manhattan_distance(to_point) as usize + 1 } else { Moves::get_best_cost(from_point, to_point, &allowed_points, &cost_map).unwrap() };
@@ -179,9 +181,21 @@ fn build_move_cost(number_of_controllers: usize) -> HashMap<(Move, Move), usize>
     new_map
 }
 
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Move::Up => f.write_char('^'),
+            Move::Down => f.write_char('v'),
+            Move::Left => f.write_char('<'),
+            Move::Right => f.write_char('>'),
+            Move::Activate => f.write_char('A'),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day21::{build_move_cost, get_code_cost, Move};
+    use crate::days::y2024::day21::{build_move_cost, get_code_cost, Move};
 
     #[test]
     fn test_build_move_cost_map() {
@@ -232,16 +246,4 @@ mod tests {
         assert_eq!(get_code_cost("456A", 2), 64);
         assert_eq!(get_code_cost("379A", 2), 64);
     }
-}
-
-impl Display for Move {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Move::Up => f.write_char('^'),
-            Move::Down => f.write_char('v'),
-            Move::Left => f.write_char('<'),
-            Move::Right => f.write_char('>'),
-            Move::Activate => f.write_char('A'),
-        }
-    }
 }
\ No newline at end of file
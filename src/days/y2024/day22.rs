@@ -1,22 +1,26 @@
 use std::collections::{HashMap, HashSet};
 use std::ops::BitXor;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::number::parse_usize;
 
-pub const DAY22: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day22;
 
-fn puzzle1(input: &String) {
-    let result: usize = input.lines().filter_map(|l| parse_usize(l).ok()).map(|v| get_nth_number(v, 2000)).sum();
-    println!("The sum of the 2000th numbers is {}", result);
-}
+impl Day for Day22 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.lines().map(parse_usize).collect()
+    }
 
-fn puzzle2(input: &String) {
-    let seeds = input.lines().filter_map(|l| parse_usize(l).ok()).collect::<Vec<_>>();
-    let best_result = get_best_income(seeds);
-    println!("The most bananas to get: {}", best_result);
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let result: usize = input.iter().map(|v| get_nth_number(*v, 2000)).sum();
+        Ok(result.into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(get_best_income(input.clone()).into())
+    }
 }
 
 fn get_next_secret_number(number: usize) -> usize {
@@ -72,7 +76,7 @@ fn get_best_income(seeds: Vec<usize>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::day22::{get_best_income, get_next_secret_number, get_nth_number};
+    use crate::days::y2024::day22::{get_best_income, get_next_secret_number, get_nth_number};
 
     #[test]
     fn test_get_nth_number() {
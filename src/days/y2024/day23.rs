@@ -1,28 +1,30 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use crate::days::Day;
-
-pub const DAY23: Day = Day {
-    puzzle1,
-    puzzle2
-};
-
-fn puzzle1(input: &String) {
-    let connections = input.lines().filter_map(|l| l.parse().ok()).collect::<Vec<Connection>>();
-    let triplets = Connection::get_triplets(&connections);
-    let result = triplets.iter().filter(|[a, b, c]| a.starts_with("t") || b.starts_with("t") || c.starts_with("t")).count();
-    println!("There are {} triplets with a t* computer.", result);
-}
+use crate::context::Context;
+use crate::days::{Answer, Day};
+
+pub struct Day23;
 
-fn puzzle2(input: &String) {
-    let connections = input.lines().filter_map(|l| l.parse().ok()).collect::<Vec<Connection>>();
+impl Day for Day23 {
+    type Input<'a> = Vec<Connection>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        input.lines().map(|l| l.parse()).collect()
+    }
 
-    let password = Connection::get_lan_password(&connections);
-    println!("LAN password: {}", password);
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let triplets = Connection::get_triplets(input);
+        let result = triplets.iter().filter(|[a, b, c]| a.starts_with("t") || b.starts_with("t") || c.starts_with("t")).count();
+        Ok(result.into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(Connection::get_lan_password(input).into())
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
-struct Connection {
+pub struct Connection {
     a: String,
     b: String,
 }
@@ -46,7 +48,7 @@ impl Connection {
         map
     }
 
-    fn get_triplets(connections: &Vec<Connection>) -> Vec<[String; 3]> {
+    fn get_triplets(connections: &[Connection]) -> Vec<[String; 3]> {
         let mut result = vec![];
 
         let mut computers = connections.iter().flat_map(|c| vec![c.a.clone(), c.b.clone()]).collect::<Vec<_>>();
@@ -102,9 +104,19 @@ impl Connection {
     }
 }
 
+impl FromStr for Connection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [a, b] = s.split('-').collect::<Vec<&str>>()[..] else { return Err(format!("Could not parse '{}'", s))};
+
+        Ok(Self { a: a.to_string(), b: b.to_string() })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day23::Connection;
+    use crate::days::y2024::day23::Connection;
 
     #[test]
     fn test_get_triplets() {
@@ -167,14 +179,4 @@ mod tests {
         tb-vc\n\
         td-yn\n\
     ";
-}
-
-impl FromStr for Connection {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let [a, b] = s.split('-').collect::<Vec<&str>>()[..] else { return Err(format!("Could not parse '{}'", s))};
-
-        Ok(Self { a: a.to_string(), b: b.to_string() })
-    }
 }
\ No newline at end of file
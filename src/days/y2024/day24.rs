@@ -1,30 +1,35 @@
 use std::collections::{HashMap};
 use std::ops::BitXor;
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::number::{parse_u8, parse_usize};
 
-pub const DAY24: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day24;
 
-fn puzzle1(input: &String) {
-    let mut machine = Machine::parse(input).unwrap();
-    machine.process_inputs();
+impl Day for Day24 {
+    type Input<'a> = Machine<'a>;
 
-    println!("Output: {}", machine.get_result());
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        Machine::parse(input)
+    }
 
-fn puzzle2(input: &String) {
-    let machine = Machine::parse(input).unwrap();
-    let swapped = machine.find_swapped_outputs();
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        let mut machine = input.clone();
+        machine.process_inputs();
+
+        Ok(machine.get_result().into())
+    }
 
-    println!("Swapped outputs: {}", swapped.join(","));
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, String> {
+        let swapped = input.find_swapped_outputs(ctx);
+
+        Ok(swapped.join(",").into())
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
-struct Machine<'a> {
+pub struct Machine<'a> {
     wires: HashMap<&'a str, u8>,
     gates: Vec<Gate<'a>>
 }
@@ -35,6 +40,7 @@ struct Wire<'a> {
     value: u8,
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 enum Operation {
     XOR, OR, AND
@@ -51,13 +57,13 @@ impl <'a> Machine<'a> {
     fn parse(input: &'a str) -> Result<Machine<'a>, String> {
         let [wires_input, gates_input] = input.split("\n\n").collect::<Vec<_>>()[..] else { return Err(format!("Invalid machine '{}'", input)) };
 
-        let wire_values: Vec<Wire<'a>> = wires_input.lines().map(|l| Wire::parse(l)).collect::<Result<_, _>>()?;
+        let wire_values: Vec<Wire<'a>> = wires_input.lines().map(Wire::parse).collect::<Result<_, _>>()?;
         let mut wires = HashMap::new();
         for wire in wire_values {
             wires.insert(wire.name, wire.value);
         }
 
-        let gates = gates_input.lines().map(|l| Gate::parse(l)).collect::<Result<_, _>>()?;
+        let gates = gates_input.lines().map(Gate::parse).collect::<Result<_, _>>()?;
 
         Ok(Self { wires, gates })
     }
@@ -68,11 +74,10 @@ impl <'a> Machine<'a> {
         // If we can't process any gate in a loop, panic.
         let mut todo = self.gates.clone();
 
-        while todo.len() > 0 {
+        while !todo.is_empty() {
             let mut handled = vec![];
 
-            for i in 0..todo.len() {
-                let gate = todo[i];
+            for (i, gate) in todo.iter().copied().enumerate() {
                 let Some(left) = self.wires.get(gate.inputs[0]) else { continue };
                 let Some(right) = self.wires.get(gate.inputs[1]) else { continue };
 
@@ -81,7 +86,7 @@ impl <'a> Machine<'a> {
                 handled.push(i);
             }
 
-            if handled.len() == 0 { panic!("No gates processed in loop!") }
+            if handled.is_empty() { panic!("No gates processed in loop!") }
             // Handle back-front (highest indexes first to remove the right items)
             while let Some(i) = handled.pop() {
                 todo.remove(i);
@@ -105,7 +110,7 @@ impl <'a> Machine<'a> {
         result
     }
 
-    fn find_swapped_outputs(&self) -> Vec<&'a str> {
+    fn find_swapped_outputs(&self, ctx: &Context) -> Vec<&'a str> {
         // There are 8 outputs (4 pairs) swapped in the main output (test output has less)
         // Doing some investigations this should just be a 'simple' circuit adding values. (Ripple carry adder)
         // Every X and Y bit should be fed into a AND and XOR gate:
@@ -144,8 +149,8 @@ impl <'a> Machine<'a> {
             .filter(|g| !g.inputs[0].starts_with(['x', 'y']) && !g.inputs[1].starts_with(['x', 'y']) && !g.output.starts_with('z') && g.operation == Operation::XOR)
             .collect::<Vec<_>>();
 
-        // println!("Wrong z-outs: {:?}", wrong_z_gates.iter().map(|c| c.output).collect::<Vec<_>>());
-        // println!("Wrong carries: {:?}", wrong_carry_gates.iter().map(|c| c.output).collect::<Vec<_>>());
+        ctx.debug(|| format!("Wrong z-outs: {:?}", wrong_z_gates.iter().map(|c| c.output).collect::<Vec<_>>()));
+        ctx.debug(|| format!("Wrong carries: {:?}", wrong_carry_gates.iter().map(|c| c.output).collect::<Vec<_>>()));
 
         let mut swap_pairs = vec![];
 
@@ -157,7 +162,7 @@ impl <'a> Machine<'a> {
             swap_pairs.push([wrong_carry.output, swap_z_gate.output]);
         }
 
-        // println!("Swaps to fix output/carries: {:?}", swap_pairs);
+        ctx.debug(|| format!("Swaps to fix output/carries: {:?}", swap_pairs));
 
         let mut test_clone = self.clone();
 
@@ -172,7 +177,7 @@ impl <'a> Machine<'a> {
         let new_result = test_clone.get_result();
 
         let difference = new_result.bitxor(z);
-        // println!("After fixing, we had {} diff ({} trailing zeroes)", difference, difference.trailing_zeros());
+        ctx.debug(|| format!("After fixing, we had {} diff ({} trailing zeroes)", difference, difference.trailing_zeros()));
 
         // Finally, we find 3 pairs, but there is still a swap between a carry and output gate, we can find that
         // by counting the zeroes at the end of our difference, which corresponds to the x and y input wires that
@@ -181,7 +186,7 @@ impl <'a> Machine<'a> {
         let [first, second] = self.gates.iter().filter(|g| g.inputs[0].ends_with(&mismapped_input.to_string()) && g.inputs[1].ends_with(&mismapped_input.to_string())).collect::<Vec<_>>()[..] else { panic!("Assumption failed") };
 
         let mut wrong_wires = vec![first.output, second.output];
-        // println!("Crossed carries: {:?}", wrong_wires);
+        ctx.debug(|| format!("Crossed carries: {:?}", wrong_wires));
         for [left, right] in swap_pairs {
             wrong_wires.push(left);
             wrong_wires.push(right);
@@ -223,10 +228,23 @@ impl <'a> Gate<'a> {
     }
 }
 
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "XOR" => Ok(Operation::XOR),
+            "OR" => Ok(Operation::OR),
+            "AND" => Ok(Operation::AND),
+            _ => Err(format!("Unknown operation '{}'", s))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
-    use crate::days::day24::{Gate, Machine, Operation};
+    use crate::days::y2024::day24::{Gate, Machine, Operation};
 
     #[test]
     fn test_parse() {
@@ -346,17 +364,4 @@ mod tests {
         tgd XOR rvg -> z12\n\
         tnw OR pbm -> gnj\n\
     ";
-}
-
-impl FromStr for Operation {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "XOR" => Ok(Operation::XOR),
-            "OR" => Ok(Operation::OR),
-            "AND" => Ok(Operation::AND),
-            _ => Err(format!("Unknown operation '{}'", s))
-        }
-    }
 }
\ No newline at end of file
@@ -1,24 +1,28 @@
 use std::str::FromStr;
-use crate::days::Day;
+use crate::context::Context;
+use crate::days::{Answer, Day};
 use crate::util::geometry::Grid;
 
-pub const DAY25: Day = Day {
-    puzzle1,
-    puzzle2
-};
+pub struct Day25;
 
-fn puzzle1(input: &String) {
-    let schematics = parse_input(input).unwrap();
+impl Day for Day25 {
+    type Input<'a> = Vec<Schematic>;
 
-    println!("There are {} unique lock/key combos that fit.", get_possible_lock_key_combos(&schematics));
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok(get_possible_lock_key_combos(input).into())
+    }
 
-fn puzzle2(_input: &String) {
-    println!("Freebie for Christmas~");
+    fn part2(_input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+        Ok("Freebie for Christmas~".into())
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-enum Schematic {
+pub enum Schematic {
     Lock([usize; 5]),
     Key([usize; 5])
 }
@@ -27,7 +31,7 @@ fn parse_input(input: &str) -> Result<Vec<Schematic>, String> {
     input.split("\n\n").map(|i| i.parse()).collect::<Result<Vec<_>, _>>()
 }
 
-fn get_possible_lock_key_combos(schematics: &Vec<Schematic>) -> usize {
+fn get_possible_lock_key_combos(schematics: &[Schematic]) -> usize {
     let locks = schematics.iter().filter_map(|s| match s {
         Schematic::Lock(l) => Some(l),
         _ => None
@@ -51,9 +55,37 @@ fn get_possible_lock_key_combos(schematics: &Vec<Schematic>) -> usize {
     result
 }
 
+impl FromStr for Schematic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.parse()?;
+
+        if grid.bounds.width != 5 { return Err(format!("Invalid input width {}", grid.bounds.width)); }
+        if grid.bounds.height != 7 { return Err(format!("Invalid input height {}", grid.bounds.height)); }
+
+        let is_lock = grid.bounds.x().all(|x| grid.get(&(x, 0).into()) == Some('#'));
+        let is_key = grid.bounds.x().all(|x| grid.get(&(x, 6).into()) == Some('#'));
+        if is_lock == is_key { return Err(format!("Could not determine if input is a lock or key?!\n{}", s)); }
+
+        let mut heights: [usize; 5] = Default::default();
+        for x in grid.bounds.x() {
+            let ys = grid.bounds.y();
+            if is_key {
+                heights[x as usize] = ys.rev().take_while(|y| grid.get(&(x, *y).into()) == Some('#')).count() - 1; // skip the border in height calcs
+            } else {
+                heights[x as usize] = ys.take_while(|y| grid.get(&(x, *y).into()) == Some('#')).count() - 1;
+            }
+
+        }
+
+        if is_lock { Ok(Self::Lock(heights)) } else { Ok(Self::Key(heights)) }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::days::day25::{get_possible_lock_key_combos, parse_input, Schematic};
+    use crate::days::y2024::day25::{get_possible_lock_key_combos, parse_input, Schematic};
 
     #[test]
     fn test_parse() {
@@ -117,32 +149,4 @@ mod tests {
         #.#.#\n\
         #####\n\
     ";
-}
-
-impl FromStr for Schematic {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid: Grid<char> = s.parse()?;
-
-        if grid.bounds.width != 5 { return Err(format!("Invalid input width {}", grid.bounds.width)); }
-        if grid.bounds.height != 7 { return Err(format!("Invalid input height {}", grid.bounds.height)); }
-
-        let is_lock = grid.bounds.x().all(|x| grid.get(&(x, 0).into()) == Some('#'));
-        let is_key = grid.bounds.x().all(|x| grid.get(&(x, 6).into()) == Some('#'));
-        if is_lock == is_key { return Err(format!("Could not determine if input is a lock or key?!\n{}", s)); }
-
-        let mut heights: [usize; 5] = Default::default();
-        for x in grid.bounds.x() {
-            let ys = grid.bounds.y();
-            if is_key {
-                heights[x as usize] = ys.rev().take_while(|y| grid.get(&(x, *y).into()) == Some('#')).count() - 1; // skip the border in height calcs
-            } else {
-                heights[x as usize] = ys.take_while(|y| grid.get(&(x, *y).into()) == Some('#')).count() - 1;
-            }
-
-        }
-
-        if is_lock { Ok(Self::Lock(heights)) } else { Ok(Self::Key(heights)) }
-    }
 }
\ No newline at end of file
@@ -1,72 +1,357 @@
 extern crate core;
 
+mod config;
+mod context;
 mod days;
+mod options;
+mod runner;
 mod util;
 
 use std::env::args;
-use days::{get_day, Day};
-use util::input::{read_input};
+use std::process::exit;
+use config::{Config, OutputFormat};
+use context::Context;
+use days::{get_day, Solution};
+use options::Options;
+use runner::{parse_days, print_benchmarks, print_benchmarks_json, print_runs, print_runs_json, Outcome};
+use util::answers::{read_answers, save_answers, Verdict};
+use util::aoc_client::{AocClient, Feedback};
+use util::input::{has_puzzle_input, save_input, InputError, InputSource};
 use util::number::{parse_i32};
+use util::submissions::{read_submissions, record_attempt, Attempt};
 
 fn print_usage()
 {
     eprintln!("
-Usage: cargo run <command> [<command_arg>, ...]
+Usage: cargo run [<year>] <command> [<command_arg>, ...] [<option>, ...]
+
+The year defaults to default_year (2024); all resources of a year live in resources/<year>.
+Settings are read from the first aoc.toml found in the working directory or its parents:
+    resources_dir, templates_dir, default_year, session_file (for the session token) and format (text or json).
+Paths in it are relative to the directory of aoc.toml.
 
 Commands:
     day <day number> - run the puzzles for the given day.
-    add <day number> - add base files and wiring for a new day.
+    day <first>..[=]<last> - run the puzzles for a range of days and summarize them in a table.
+    all - run the puzzles for all days and summarize them in a table.
+    add <day number> [--template <name>] [--html <path>] - add the source file, input, example and answers for a new day;
+        build.rs picks the day up. Templates are in resources/templates: default, grid, parser or graph.
+        A saved puzzle description (--html) provides the title and example input.
+    remove <day number> - remove a day added by add, as long as it still only holds the template.
+    fetch <day number> - download the input for a day to resources/<year>/dayNN.txt (needs AOC_SESSION or .aoc_session).
+    bench <day number> [--iterations <n>] - time the puzzles for the given day over a number of runs (default 10).
+    submit <day number> <puzzle> - submit the answer of a puzzle (1 or 2); every attempt is logged in resources/<year>/submissions.
+    verify [<day number>|all] - check puzzle answers against the ones recorded in resources/<year>/answers.
+
+Input options (day, all and bench; by default resources/<year>/dayNN.txt is used):
+    --input <path> - read the input from the given file.
+    --stdin - read the input from stdin.
+    --example [<n>] - use example input n (default 1) from resources/<year>/examples/dayNN_n.txt.
+
+Output options (day, all and bench):
+    --format text|json - print results as text, or as JSON lines with one object per day and part.
+
+Diagnostic options (day, all, submit and verify; diagnostics go to stderr, never between the answers):
+    --verbose - also show debug output of the days.
+    --quiet - show no diagnostic output at all.
+    --visualize - show the frames days render of their progress (e.g. day 14).
+    --play - replay the frames of every part in the terminal once it is done: space plays or pauses,
+        the arrow keys step back and forth or change the speed, g and G go to the first and last frame and q stops.
+        Days that step through a simulation only show how it ends, unless --frames picks the steps to play
+        (e.g. --play --frames all for the walk of the guard in day 6).
+    --export <path> - save the frames as images instead: .ppm, .pgm or .png files named after the path, the part and the frame,
+        or an animation per part for a .gif path.
+    --scale <n> - blow exported frames up, drawing every pixel as an n by n square.
+    --frames <steps> - the steps to render frames of, where a day supports it: all, or steps (5) and ranges (1..10, 1..=10)
+        separated by commas. By default a day shows what it sees fit, like the tree of day 14.
+        Steps are the moves of the guard in day 6, seconds in day 14, moves of the robot in day 15 and bytes fallen in day 18.
+    --interactive - let days ask for confirmation where they would otherwise rely on a heuristic (e.g. the tree of day 14).
 ");
 }
 
 fn main() {
-    let a: Vec<String> = args().collect();
-
-    if a.len() < 3 {
-        print_usage();
-        return;
+    match Config::discover() {
+        Ok(config) => Config::init(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            return;
+        }
     }
 
-    match a[1].as_str() {
-        "day" => {
-            run_day(&a[2])
+    let a: Vec<String> = args().skip(1).collect();
+    let options = match Options::parse(&a) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}", err);
+            print_usage();
+            return;
+        }
+    };
+    let ctx = match options.context() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("{}", err);
+            return;
+        }
+    };
+
+    match (options.argument(0), options.argument(1)) {
+        (Some("day"), Some(day)) if day.contains("..") => {
+            run_days(day, &options, &ctx)
+        }
+        (Some("day"), Some(day)) => {
+            run_day(day, &options, &ctx)
+        }
+        (Some("all"), _) => {
+            run_days("all", &options, &ctx)
+        }
+        (Some("add"), Some(day)) => {
+            add_day(day, &options)
         }
-        "add" => {
-            add_day(&a[2])
+        (Some("remove"), Some(day)) => {
+            remove_day(day, &options)
+        }
+        (Some("fetch"), Some(day)) => {
+            fetch_day(day, &options)
+        }
+        (Some("bench"), Some(day)) => {
+            bench_day(day, &options)
+        }
+        (Some("submit"), Some(day)) => {
+            submit_answer(day, &options, &ctx)
+        }
+        (Some("verify"), day) => {
+            verify_days(day.unwrap_or("all"), &options, &ctx)
         }
         _ => {
             print_usage();
         }
     }
+
+    finish(&ctx);
 }
 
-fn run_day(day_num: &str)
+fn finish(ctx: &Context)
 {
-    let result: Result<(String, Day), String> = parse_i32(day_num)
-        .and_then(|d| get_day(d).and_then(|day| read_input(d).and_then(|input| Ok((input, day)))));
+    if let Err(err) = ctx.finish() {
+        eprintln!("{}", err);
+    }
+}
+
+fn run_day(day_num: &str, options: &Options, ctx: &Context)
+{
+    let result = parse_i32(day_num).and_then(|d| load_day(options.year(), d, &options.input).map(|(day, input)| (d, day, input)));
     match result {
-        Ok((input, day)) => {
-            (day.puzzle1)(&input);
-            (day.puzzle2)(&input);
+        Ok((day_num, day, input)) => {
+            let run = runner::run_day(day_num, day.as_ref(), &input, ctx);
+            if options.format() == OutputFormat::Json {
+                print_runs_json(options.year(), &[run]);
+                return;
+            }
+
+            if let Some(err) = run.parse.error() {
+                eprintln!("Could not parse input: {}", err);
+            }
+            for puzzle in run.puzzles {
+                match puzzle.outcome {
+                    Outcome::Solved(answer) => println!("Puzzle {}: {}", puzzle.puzzle, answer),
+                    outcome => eprintln!("Puzzle {} failed: {}", puzzle.puzzle, outcome),
+                }
+            }
         }
+        Err(err) => match (options.format(), parse_i32(day_num)) {
+            (OutputFormat::Json, Ok(day_num)) => print_runs_json(options.year(), &[runner::failed_day(day_num, err)]),
+            _ => eprintln!("{}", err),
+        }
+    }
+}
+
+fn run_days(selection: &str, options: &Options, ctx: &Context)
+{
+    if matches!(options.input, InputSource::File(_) | InputSource::Stdin) {
+        eprintln!("Only --example can be used when running multiple days");
+        return;
+    }
+
+    let day_nums = match parse_days(options.year(), selection) {
+        Ok(v) => v,
         Err(err) => {
             eprintln!("{}", err);
+            return;
+        }
+    };
+
+    let mut runs = vec![];
+    for day_num in day_nums {
+        match load_day(options.year(), day_num, &options.input) {
+            Ok((day, input)) => runs.push(runner::run_day(day_num, day.as_ref(), &input, ctx)),
+            Err(err) => runs.push(runner::failed_day(day_num, err)),
         }
     }
+
+    match options.format() {
+        OutputFormat::Text => print_runs(&runs),
+        OutputFormat::Json => print_runs_json(options.year(), &runs),
+    }
 }
 
-fn add_day(input: &str)
+fn bench_day(day_num: &str, options: &Options)
 {
-    // This is going to be fun. Write code to modify the running code! Woohoo!
-    match parse_i32(input) {
-        Ok(day) => {
-            match util::create_day::create_day(day) {
-                Ok(_) => { println!("Successfully added day {}", day); }
-                Err(e) => { panic!("{}", e); }
+    let iterations = options.iterations.unwrap_or(10);
+    match parse_i32(day_num).and_then(|d| load_day(options.year(), d, &options.input).map(|(day, input)| (d, day, input))) {
+        Ok((day_num, day, input)) => match options.format() {
+            OutputFormat::Text => {
+                println!("Benchmarking day {} over {} iterations", day_num, iterations);
+                print_benchmarks(&runner::bench_day(day.as_ref(), &input, iterations));
             }
+            OutputFormat::Json => print_benchmarks_json(options.year(), day_num, iterations, &runner::bench_day(day.as_ref(), &input, iterations)),
+        },
+        Err(err) => {
+            eprintln!("{}", err);
         }
+    }
+}
+
+fn verify_days(target: &str, options: &Options, ctx: &Context)
+{
+    let year = options.year();
+    let day_nums: Vec<i32> = match parse_days(year, target) {
+        Ok(v) => v,
+        Err(err) => {
+            eprintln!("{}", err);
+            return;
+        }
+    };
+
+    let (mut passed, mut failed, mut missing, mut without_input) = (0, 0, 0, 0);
+    for day_num in day_nums {
+        // Without its puzzle input there is nothing to verify for a day, but nothing failed either.
+        let input = match InputSource::Puzzle.read(year, day_num) {
+            Ok(input) => Ok(input),
+            Err(err @ (InputError::Missing(_) | InputError::Empty(_) | InputError::Placeholder(_))) => {
+                println!("Day {:2}: {}", day_num, err);
+                without_input += 1;
+                continue;
+            }
+            Err(err) => Err(err.to_string()),
+        };
+        let result = input.and_then(|input| Ok((get_day(year, day_num)?, input, read_answers(year, day_num)?)));
+        let (day, input, answers) = match result {
+            Ok(v) => v,
+            Err(err) => {
+                println!("Day {:2}: {}", day_num, err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let run = runner::run_day(day_num, day.as_ref(), &input, ctx);
+        if let Some(err) = run.parse.error() {
+            println!("Day {:2}: could not parse input: {}", day_num, err);
+            failed += 1;
+            continue;
+        }
+
+        for puzzle in run.puzzles {
+            let verdict = answers.verify(puzzle.puzzle, &puzzle.outcome.result());
+            println!("Day {:2} puzzle {}: {}", day_num, puzzle.puzzle, verdict);
+
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Missing(_) => missing += 1,
+                _ => failed += 1,
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed, {} missing, {} days without input", passed, failed, missing, without_input);
+    if failed > 0 {
+        finish(ctx);
+        exit(1);
+    }
+}
+
+fn load_day(year: i32, day_num: i32, input: &InputSource) -> Result<(Box<dyn Solution>, String), String>
+{
+    let day = get_day(year, day_num)?;
+    let input = input.read(year, day_num)?;
+
+    Ok((day, input))
+}
+
+fn fetch_day(day_num: &str, options: &Options)
+{
+    let year = options.year();
+    let result = parse_i32(day_num).and_then(|day| {
+        // The stored input doubles as cache: never ask for an input we already have.
+        if has_puzzle_input(year, day)? {
+            return Err(format!("Input for day {} already exists, not fetching it again.", day));
+        }
+
+        let input = AocClient::from_env()?.fetch_input(year, day)?;
+        save_input(year, day, &input).map(|_| day)
+    });
+
+    match result {
+        Ok(day) => println!("Fetched input for day {}", day),
+        Err(err) => eprintln!("{}", err),
+    }
+}
+
+fn submit_answer(day_num: &str, options: &Options, ctx: &Context)
+{
+    let year = options.year();
+    let result = parse_i32(day_num).and_then(|day_num| {
+        let puzzle = match options.argument(2) {
+            Some("1") => 1,
+            Some("2") => 2,
+            _ => return Err("Expected the puzzle to submit: 1 or 2".to_string()),
+        };
+
+        // Always answer with the real puzzle input, it's the only one the server knows about.
+        let (day, input) = load_day(year, day_num, &InputSource::Puzzle)?;
+        ctx.start_part(day_num, puzzle);
+        let answer = day.parse(&input)?.part(puzzle, ctx)?.to_string();
+
+        // Never bother the server with answers we already know to be wrong.
+        read_submissions(year, day_num)?.check(puzzle, &answer)?;
+
+        println!("Submitting {} for day {} puzzle {}", answer, day_num, puzzle);
+        let response = AocClient::from_env()?.submit_answer(year, day_num, puzzle, &answer)?;
+        record_attempt(year, day_num, &Attempt { puzzle, feedback: response.feedback, answer: answer.clone() })?;
+
+        if response.feedback == Feedback::Correct {
+            let mut answers = read_answers(year, day_num)?;
+            answers.set(puzzle, answer);
+            save_answers(year, day_num, &answers)?;
+        }
+
+        Ok(response)
+    });
+
+    match result {
+        Ok(response) => println!("{}: {}", response.feedback, response.message),
+        Err(err) => eprintln!("{}", err),
+    }
+}
+
+fn remove_day(day_num: &str, options: &Options)
+{
+    match parse_i32(day_num).and_then(|day| util::create_day::remove_day(options.year(), day).map(|_| day)) {
+        Ok(day) => println!("Removed day {}", day),
+        Err(err) => eprintln!("{}", err),
+    }
+}
+
+fn add_day(input: &str, options: &Options)
+{
+    // This is going to be fun. Write code to modify the running code! Woohoo!
+    let template = options.template.as_deref().unwrap_or("default");
+    match parse_i32(input).and_then(|day| util::create_day::create_day(options.year(), day, template, options.html.as_deref()).map(|_| day)) {
+        Ok(day) => println!("Successfully added day {}", day),
         Err(err) => {
-            panic!("{}", err);
+            eprintln!("{}", err);
+            exit(1);
         }
     }
-}
\ No newline at end of file
+}
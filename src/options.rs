@@ -0,0 +1,222 @@
+use std::iter::Peekable;
+use std::slice::Iter;
+use crate::config::{Config, OutputFormat};
+use crate::context::{Context, ImageSink, LogLevel, PlayerSink, Steps, TerminalSink};
+use crate::util::input::InputSource;
+use crate::util::number::parse_usize;
+
+/// The first Advent of Code was held in 2015; no day number comes near it.
+const FIRST_YEAR: i32 = 2015;
+
+/// Command line arguments, split into the year, the positional arguments and the `--` options.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct Options {
+    pub year: Option<i32>,
+    pub arguments: Vec<String>,
+    pub iterations: Option<usize>,
+    pub input: InputSource,
+    pub template: Option<String>,
+    pub html: Option<String>,
+    pub format: Option<OutputFormat>,
+    pub log_level: Option<LogLevel>,
+    pub visualize: bool,
+    pub play: bool,
+    pub export: Option<String>,
+    pub scale: Option<usize>,
+    pub frames: Option<Steps>,
+    pub interactive: bool,
+}
+
+impl Options {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--iterations" => options.iterations = Some(parse_usize(next_value(&mut args, arg)?)?),
+                "--input" => options.set_input(InputSource::File(next_value(&mut args, arg)?.to_string()))?,
+                "--template" => options.template = Some(next_value(&mut args, arg)?.to_string()),
+                "--html" => options.html = Some(next_value(&mut args, arg)?.to_string()),
+                "--format" => options.format = Some(next_value(&mut args, arg)?.parse()?),
+                "--stdin" => options.set_input(InputSource::Stdin)?,
+                "--verbose" => options.set_log_level(LogLevel::Debug)?,
+                "--quiet" => options.set_log_level(LogLevel::Quiet)?,
+                "--visualize" => options.visualize = true,
+                "--play" => options.play = true,
+                "--export" => options.export = Some(next_value(&mut args, arg)?.to_string()),
+                "--scale" => options.scale = Some(parse_usize(next_value(&mut args, arg)?)?),
+                "--frames" => options.frames = Some(next_value(&mut args, arg)?.parse()?),
+                "--interactive" => options.interactive = true,
+                "--example" => {
+                    // The example number is optional, and defaults to the first example.
+                    let example = match args.peek().map(|v| parse_usize(v)) {
+                        Some(Ok(example)) => {
+                            args.next();
+                            example
+                        }
+                        _ => 1
+                    };
+                    options.set_input(InputSource::Example(example))?
+                }
+                option if option.starts_with("--") => return Err(format!("Unknown option '{}'", option)),
+                _ => options.arguments.push(arg.clone()),
+            }
+        }
+
+        // The year may precede the command, as in `2024 day 5`.
+        if let Some(Ok(year)) = options.arguments.first().map(|a| a.parse::<i32>()) {
+            if year >= FIRST_YEAR {
+                options.year = Some(year);
+                options.arguments.remove(0);
+            }
+        }
+
+        Ok(options)
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year.unwrap_or(Config::get().default_year)
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format.unwrap_or(Config::get().format)
+    }
+
+    /// The context for the days to run in: what they log, and where their frames go.
+    pub fn context(&self) -> Result<Context, String> {
+        let ctx = Context::new(self.log_level.unwrap_or_default())
+            .with_steps(self.frames.clone())
+            .with_interactive(self.interactive);
+
+        match (self.visualize, self.play, &self.export) {
+            (false, false, None) => Ok(ctx),
+            (true, false, None) => Ok(ctx.with_sink(Box::new(TerminalSink))),
+            (false, true, None) => Ok(ctx.with_sink(Box::new(PlayerSink::new()?))),
+            (false, false, Some(path)) => Ok(ctx.with_sink(Box::new(ImageSink::new(path)?.with_scale(self.scale.unwrap_or(1))))),
+            _ => Err("Only one of --visualize, --play and --export can be given".to_string()),
+        }
+    }
+
+    pub fn argument(&self, index: usize) -> Option<&str> {
+        self.arguments.get(index).map(|a| a.as_str())
+    }
+
+    fn set_input(&mut self, input: InputSource) -> Result<(), String> {
+        if self.input != InputSource::Puzzle {
+            return Err("Only one of --input, --stdin and --example can be given".to_string());
+        }
+
+        self.input = input;
+        Ok(())
+    }
+
+    fn set_log_level(&mut self, level: LogLevel) -> Result<(), String> {
+        if self.log_level.is_some() {
+            return Err("Only one of --verbose and --quiet can be given".to_string());
+        }
+
+        self.log_level = Some(level);
+        Ok(())
+    }
+}
+
+fn next_value<'a>(args: &mut Peekable<Iter<'a, String>>, option: &str) -> Result<&'a str, String> {
+    args.next().map(|v| v.as_str()).ok_or(format!("Expected a value after '{}'", option))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::OutputFormat;
+    use crate::context::{LogLevel, Steps};
+    use crate::days::DEFAULT_YEAR;
+    use crate::options::Options;
+    use crate::util::input::InputSource;
+
+    fn parse(args: &str) -> Result<Options, String> {
+        Options::parse(&args.split_whitespace().map(|a| a.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_parse_arguments() {
+        let options = parse("bench 5 --iterations 20").unwrap();
+        assert_eq!(options.arguments, vec!["bench".to_string(), "5".to_string()]);
+        assert_eq!(options.iterations, Some(20));
+        assert_eq!(options.input, InputSource::Puzzle);
+        assert_eq!(options.argument(1), Some("5"));
+        assert_eq!(options.argument(2), None);
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let options = parse("2023 day 5").unwrap();
+        assert_eq!(options.year(), 2023);
+        assert_eq!(options.arguments, vec!["day".to_string(), "5".to_string()]);
+
+        let options = parse("day 5").unwrap();
+        assert_eq!(options.year, None);
+        assert_eq!(options.year(), DEFAULT_YEAR);
+        assert_eq!(parse("5 day").unwrap().year, None);
+    }
+
+    #[test]
+    fn test_parse_input_sources() {
+        assert_eq!(parse("day 5 --input other.txt").unwrap().input, InputSource::File("other.txt".to_string()));
+        assert_eq!(parse("day 5 --stdin").unwrap().input, InputSource::Stdin);
+        assert_eq!(parse("day 5 --example").unwrap().input, InputSource::Example(1));
+        assert_eq!(parse("day 5 --example 2").unwrap().input, InputSource::Example(2));
+        assert_eq!(parse("day --example 5").unwrap().arguments, vec!["day".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_add_options() {
+        let options = parse("add 5 --template grid --html day05.html").unwrap();
+        assert_eq!(options.template, Some("grid".to_string()));
+        assert_eq!(options.html, Some("day05.html".to_string()));
+        assert_eq!(parse("add 5").unwrap().template, None);
+        assert!(parse("add 5 --template").is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(parse("all --format json").unwrap().format(), OutputFormat::Json);
+        assert_eq!(parse("all --format text").unwrap().format(), OutputFormat::Text);
+        assert_eq!(parse("all").unwrap().format, None);
+        assert!(parse("all --format xml").is_err());
+    }
+
+    #[test]
+    fn test_parse_output_levels() {
+        assert_eq!(parse("day 5 --verbose").unwrap().log_level, Some(LogLevel::Debug));
+        assert_eq!(parse("day 5 --quiet").unwrap().log_level, Some(LogLevel::Quiet));
+        assert!(!parse("day 5").unwrap().context().unwrap().is_enabled(LogLevel::Debug));
+        assert!(!parse("day 5").unwrap().context().unwrap().is_visualizing());
+        assert!(parse("day 14 --visualize").unwrap().context().unwrap().is_visualizing());
+        assert!(parse("day 14 --interactive").unwrap().context().unwrap().is_interactive());
+        assert!(!parse("all").unwrap().context().unwrap().is_interactive());
+    }
+
+    #[test]
+    fn test_parse_export() {
+        let ctx = parse("day 14 --export frames/day14.png --frames 1..=3").unwrap().context().unwrap();
+        assert!(ctx.is_visualizing());
+        assert_eq!(ctx.steps(), Some(&Steps::Ranges(vec![(1, 3)])));
+
+        assert!(parse("day 14 --export day14.jpg").unwrap().context().is_err());
+        assert!(parse("day 14 --export day14.gif --visualize").unwrap().context().is_err());
+        assert!(parse("day 15 --play --visualize").unwrap().context().is_err());
+        assert!(parse("day 15 --play").unwrap().play);
+        assert!(parse("day 14 --frames x").is_err());
+        assert_eq!(parse("day 18 --export day18.png --scale 4").unwrap().scale, Some(4));
+        assert!(parse("day 18 --scale big").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("day 5 --input").is_err());
+        assert!(parse("day 5 --stdin --example").is_err());
+        assert!(parse("day 5 --verbose --quiet").is_err());
+        assert!(parse("day 5 --iterations many").is_err());
+        assert!(parse("day 5 --frobnicate").is_err());
+    }
+}
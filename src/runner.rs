@@ -0,0 +1,444 @@
+use std::any::Any;
+use std::fmt;
+use std::panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe, UnwindSafe};
+use std::time::{Duration, Instant};
+use serde_json::{json, Value};
+use crate::context::Context;
+use crate::days::{get_day, Answer, Solution};
+use crate::util::number::parse_i32;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Outcome<T = Answer> {
+    Solved(T),
+    Failed(String),
+    Panicked(String),
+}
+
+impl<T> Outcome<T> {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "ok",
+            Outcome::Failed(_) => "error",
+            Outcome::Panicked(_) => "panic",
+        }
+    }
+
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Solved(_) => None,
+            Outcome::Failed(err) => Some(err.clone()),
+            Outcome::Panicked(msg) => Some(format!("panicked: {}", msg)),
+        }
+    }
+
+    fn capture<F>(f: F) -> (Self, Duration) where F: FnOnce() -> Result<T, String> + UnwindSafe {
+        let start = Instant::now();
+        let outcome = match catch_panic(f) {
+            Ok(Ok(value)) => Outcome::Solved(value),
+            Ok(Err(err)) => Outcome::Failed(err),
+            Err(msg) => Outcome::Panicked(msg),
+        };
+
+        (outcome, start.elapsed())
+    }
+}
+
+impl Outcome {
+    pub fn result(&self) -> Result<Answer, String> {
+        match self {
+            Outcome::Solved(answer) => Ok(answer.clone()),
+            outcome => Err(outcome.error().unwrap_or_default()),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.result() {
+            Ok(answer) => write!(f, "{}", answer),
+            Err(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleRun {
+    pub puzzle: usize,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// The result of running a day: its input is parsed once, after which both puzzles run on it.
+/// When parsing fails, no puzzles are run.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: i32,
+    /// The stage before the puzzles: `parse`, or `load` when the day or its input could not be loaded.
+    pub stage: &'static str,
+    pub parse: Outcome<()>,
+    pub parse_elapsed: Duration,
+    pub puzzles: Vec<PuzzleRun>,
+}
+
+pub fn registered_days(year: i32) -> Vec<i32> {
+    (1..=25).filter(|d| get_day(year, *d).is_ok()).collect()
+}
+
+/// Parses a day selection: `all` (days of the year), a single day (`5`) or a range (`1..10`, `1..=10`).
+pub fn parse_days(year: i32, selection: &str) -> Result<Vec<i32>, String> {
+    if selection == "all" {
+        return Ok(registered_days(year));
+    }
+
+    if let Some((start, end)) = selection.split_once("..=") {
+        Ok((parse_i32(start)?..=parse_i32(end)?).collect())
+    } else if let Some((start, end)) = selection.split_once("..") {
+        Ok((parse_i32(start)?..parse_i32(end)?).collect())
+    } else {
+        parse_i32(selection).map(|d| vec![d])
+    }
+}
+
+pub fn run_day(day_num: i32, solution: &dyn Solution, input: &str, ctx: &Context) -> DayRun {
+    let mut parsed = None;
+    let (parse, parse_elapsed) = Outcome::capture(AssertUnwindSafe(|| {
+        parsed = Some(solution.parse(input)?);
+        Ok(())
+    }));
+
+    let puzzles = match parsed {
+        Some(parsed) => [1, 2].into_iter().map(|puzzle| {
+            ctx.start_part(day_num, puzzle);
+            let (outcome, elapsed) = Outcome::capture(AssertUnwindSafe(|| parsed.part(puzzle, ctx)));
+            PuzzleRun { puzzle, outcome, elapsed }
+        }).collect(),
+        None => vec![],
+    };
+
+    DayRun { day: day_num, stage: "parse", parse, parse_elapsed, puzzles }
+}
+
+/// A day that could not be loaded, so nothing was parsed or run.
+pub fn failed_day(day_num: i32, err: String) -> DayRun {
+    DayRun { day: day_num, stage: "load", parse: Outcome::Failed(err), parse_elapsed: Duration::ZERO, puzzles: vec![] }
+}
+
+/// Runs the given closure, turning a panic into an `Err` with the panic message. The default
+/// panic hook is silenced meanwhile, so a panicking day does not clutter the output.
+pub fn catch_panic<F, R>(f: F) -> Result<R, String> where F: FnOnce() -> R + UnwindSafe {
+    let hook = take_hook();
+    set_hook(Box::new(|_| {}));
+    let result = catch_unwind(f);
+    set_hook(hook);
+
+    result.map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let min = *sorted.first()?;
+        let max = *sorted.last()?;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+
+        Some(Self { min, median, mean, max })
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Benchmark {
+    pub stage: &'static str,
+    pub timings: Result<Timings, String>,
+}
+
+/// Parses the input and runs both puzzles of a day `iterations` times each and collects their
+/// timings. A stage that fails or panics is not benchmarked any further; its error is returned
+/// instead. When parsing fails, the puzzles are not benchmarked at all. The puzzles run without any
+/// diagnostic output, which would only skew the timings.
+pub fn bench_day(solution: &dyn Solution, input: &str, iterations: usize) -> Vec<Benchmark> {
+    let mut parsed = None;
+    let parse = bench_stage(iterations, || {
+        parsed = Some(solution.parse(input)?);
+        Ok(())
+    });
+
+    let mut benchmarks = vec![Benchmark { stage: "parse", timings: parse }];
+    if let Some(parsed) = parsed {
+        let ctx = Context::quiet();
+        for (stage, puzzle) in [("1", 1), ("2", 2)] {
+            let timings = bench_stage(iterations, || parsed.part(puzzle, &ctx).map(|_| ()));
+            benchmarks.push(Benchmark { stage, timings });
+        }
+    }
+
+    benchmarks
+}
+
+fn bench_stage<F>(iterations: usize, mut stage: F) -> Result<Timings, String> where F: FnMut() -> Result<(), String> {
+    let mut samples = vec![];
+    for _ in 0..iterations {
+        let (outcome, elapsed) = Outcome::capture(AssertUnwindSafe(&mut stage));
+        if let Some(err) = outcome.error() {
+            return Err(err);
+        }
+        samples.push(elapsed);
+    }
+
+    Timings::from_samples(&samples).ok_or("No iterations were run".to_string())
+}
+
+impl DayRun {
+    /// The run as JSON objects: one for parsing (or loading), and one per puzzle.
+    pub fn to_json(&self, year: i32) -> Vec<Value> {
+        let parse = json!({
+            "year": year,
+            "day": self.day,
+            "part": self.stage,
+            "answer": null,
+            "time_ns": self.parse_elapsed.as_nanos() as u64,
+            "status": self.parse.status(),
+            "error": self.parse.error(),
+        });
+        let puzzles = self.puzzles.iter().map(|puzzle| json!({
+            "year": year,
+            "day": self.day,
+            "part": puzzle.puzzle.to_string(),
+            "answer": match &puzzle.outcome {
+                Outcome::Solved(Answer::Number(n)) => json!(n),
+                Outcome::Solved(Answer::Text(t)) => json!(t),
+                _ => Value::Null,
+            },
+            "time_ns": puzzle.elapsed.as_nanos() as u64,
+            "status": puzzle.outcome.status(),
+            "error": puzzle.outcome.error(),
+        }));
+
+        [parse].into_iter().chain(puzzles).collect()
+    }
+}
+
+/// Prints the runs as JSON lines, one per day and part.
+pub fn print_runs_json(year: i32, runs: &[DayRun]) {
+    for line in runs.iter().flat_map(|run| run.to_json(year)) {
+        println!("{}", line);
+    }
+}
+
+pub fn print_runs(runs: &[DayRun]) {
+    print_table(["Day", "Part", "Answer", "Time", "Status"], &run_rows(runs));
+}
+
+/// The rows of the summary table: one for parsing (or loading) every day, and one per puzzle.
+fn run_rows(runs: &[DayRun]) -> Vec<[String; 5]> {
+    runs.iter().flat_map(|run| {
+        let parse_row = [
+            run.day.to_string(),
+            run.stage.to_string(),
+            run.parse.error().unwrap_or_default(),
+            format!("{:.2?}", run.parse_elapsed),
+            run.parse.status().to_string(),
+        ];
+        let puzzle_rows = run.puzzles.iter().map(|puzzle| [
+            run.day.to_string(),
+            puzzle.puzzle.to_string(),
+            puzzle.outcome.to_string(),
+            format!("{:.2?}", puzzle.elapsed),
+            puzzle.outcome.status().to_string(),
+        ]);
+
+        [parse_row].into_iter().chain(puzzle_rows).collect::<Vec<_>>()
+    }).collect()
+}
+
+pub fn print_benchmarks(benchmarks: &[Benchmark]) {
+    let rows: Vec<[String; 5]> = benchmarks.iter().map(|b| match &b.timings {
+        Ok(t) => [b.stage.to_string(), format!("{:.2?}", t.min), format!("{:.2?}", t.median), format!("{:.2?}", t.mean), format!("{:.2?}", t.max)],
+        Err(err) => [b.stage.to_string(), err.clone(), String::new(), String::new(), String::new()],
+    }).collect();
+
+    print_table(["Part", "Min", "Median", "Mean", "Max"], &rows);
+}
+
+/// Prints the benchmarks as JSON lines, one per part; timings are in nanoseconds.
+pub fn print_benchmarks_json(year: i32, day: i32, iterations: usize, benchmarks: &[Benchmark]) {
+    for benchmark in benchmarks {
+        let ns = |d: Duration| d.as_nanos() as u64;
+        let line = match &benchmark.timings {
+            Ok(t) => json!({
+                "year": year, "day": day, "part": benchmark.stage, "iterations": iterations, "status": "ok",
+                "min_ns": ns(t.min), "median_ns": ns(t.median), "mean_ns": ns(t.mean), "max_ns": ns(t.max), "error": null,
+            }),
+            Err(err) => json!({
+                "year": year, "day": day, "part": benchmark.stage, "iterations": iterations, "status": "error",
+                "min_ns": null, "median_ns": null, "mean_ns": null, "max_ns": null, "error": err,
+            }),
+        };
+        println!("{}", line);
+    }
+}
+
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let header = header.map(|h| h.to_string());
+    let widths: Vec<usize> = (0..N)
+        .map(|col| rows.iter().chain([&header]).map(|r| r[col].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let format_row = |row: &[String; N]| row.iter().zip(&widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect::<Vec<_>>()
+        .join(" | ");
+
+    println!("{}", format_row(&header));
+    println!("{}", widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().join("-+-"));
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+    use crate::days::{Answer, Day};
+    use serde_json::json;
+    use std::time::Duration;
+    use crate::runner::{bench_day, catch_panic, failed_day, parse_days, run_day, run_rows, Outcome, Timings};
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days(2024, "5"), Ok(vec![5]));
+        assert_eq!(parse_days(2024, "3..6"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days(2024, "3..=6"), Ok(vec![3, 4, 5, 6]));
+        assert!(parse_days(2024, "all").unwrap().contains(&1));
+        assert_eq!(parse_days(1999, "all"), Ok(vec![]));
+        assert!(parse_days(2024, "x..4").is_err());
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(catch_panic(|| -> usize { panic!("Oh no") }), Err("Oh no".to_string()));
+        assert_eq!(catch_panic(|| -> usize { panic!("Oh {}", "no") }), Err("Oh no".to_string()));
+    }
+
+    struct TestDay;
+
+    impl Day for TestDay {
+        type Input<'a> = &'a str;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, String> {
+            match input {
+                "" => Err("Empty input".to_string()),
+                input => Ok(input),
+            }
+        }
+
+        fn part1(input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+            Ok(Answer::Text(input.to_string()))
+        }
+
+        fn part2(_input: &Self::Input<'_>, _ctx: &Context) -> Result<Answer, String> {
+            panic!("Not yet")
+        }
+    }
+
+    #[test]
+    fn test_run_day() {
+        let run = run_day(3, &TestDay, "foo", &Context::default());
+        assert_eq!(run.parse, Outcome::Solved(()));
+        assert_eq!(run.puzzles.len(), 2);
+        assert_eq!(run.puzzles[0].outcome, Outcome::Solved(Answer::Text("foo".to_string())));
+        assert_eq!(run.puzzles[1].outcome, Outcome::Panicked("Not yet".to_string()));
+
+        let run = run_day(3, &TestDay, "", &Context::default());
+        assert_eq!(run.parse, Outcome::Failed("Empty input".to_string()));
+        assert!(run.puzzles.is_empty());
+    }
+
+    #[test]
+    fn test_run_to_json() {
+        let mut run = run_day(3, &TestDay, "foo", &Context::default());
+        run.parse_elapsed = Duration::from_nanos(1500);
+        run.puzzles[0].elapsed = Duration::from_micros(2);
+        run.puzzles[1].elapsed = Duration::from_micros(3);
+
+        assert_eq!(run.to_json(2024), vec![
+            json!({ "year": 2024, "day": 3, "part": "parse", "answer": null, "time_ns": 1500, "status": "ok", "error": null }),
+            json!({ "year": 2024, "day": 3, "part": "1", "answer": "foo", "time_ns": 2000, "status": "ok", "error": null }),
+            json!({ "year": 2024, "day": 3, "part": "2", "answer": null, "time_ns": 3000, "status": "panic", "error": "panicked: Not yet" }),
+        ]);
+
+        let run = run_day(3, &TestDay, "", &Context::default());
+        let json = run.to_json(2024);
+        assert_eq!(json.len(), 1);
+        assert_eq!(json[0]["status"], "error");
+        assert_eq!(json[0]["error"], "Empty input");
+
+        let run = failed_day(4, "Could not read input".to_string());
+        assert_eq!(run.to_json(2024), vec![
+            json!({ "year": 2024, "day": 4, "part": "load", "answer": null, "time_ns": 0, "status": "error", "error": "Could not read input" }),
+        ]);
+    }
+
+    #[test]
+    fn test_run_rows() {
+        let mut run = run_day(3, &TestDay, "foo", &Context::default());
+        run.parse_elapsed = Duration::from_micros(1);
+        run.puzzles[0].elapsed = Duration::from_micros(2);
+        run.puzzles[1].elapsed = Duration::from_micros(3);
+        let rows = run_rows(&[run, failed_day(4, "Could not read input".to_string())]);
+
+        assert_eq!(rows, vec![
+            ["3", "parse", "", "1.00µs", "ok"].map(String::from),
+            ["3", "1", "foo", "2.00µs", "ok"].map(String::from),
+            ["3", "2", "panicked: Not yet", "3.00µs", "panic"].map(String::from),
+            ["4", "load", "Could not read input", "0.00ns", "error"].map(String::from),
+        ]);
+    }
+
+    #[test]
+    fn test_timings_from_samples() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(Timings::from_samples(&[ms(4), ms(1), ms(3)]), Some(Timings { min: ms(1), median: ms(3), mean: Duration::from_nanos(2_666_666), max: ms(4) }));
+        assert_eq!(Timings::from_samples(&[ms(4), ms(1), ms(3), ms(8)]), Some(Timings { min: ms(1), median: Duration::from_micros(3500), mean: ms(4), max: ms(8) }));
+        assert_eq!(Timings::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let benchmarks = bench_day(&TestDay, "foo", 3);
+        assert_eq!(benchmarks.len(), 3);
+        assert!(benchmarks[0].timings.is_ok());
+        assert!(benchmarks[1].timings.is_ok());
+        assert_eq!(benchmarks[2].timings, Err("panicked: Not yet".to_string()));
+
+        let benchmarks = bench_day(&TestDay, "", 3);
+        assert_eq!(benchmarks.len(), 1);
+        assert_eq!(benchmarks[0].timings, Err("Empty input".to_string()));
+    }
+}
@@ -1,6 +1,16 @@
 pub mod input;
 pub mod number;
 pub mod geometry;
+pub mod image;
+pub mod render;
+pub mod player;
+pub mod search;
+pub mod disjoint_set;
 pub mod create_day;
 pub mod collection;
-pub mod parser;
\ No newline at end of file
+pub mod parser;
+pub mod answers;
+pub mod aoc_client;
+pub mod submissions;
+pub mod puzzle_page;
+pub mod file_transaction;
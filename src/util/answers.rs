@@ -0,0 +1,165 @@
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::Path;
+use std::str::FromStr;
+use crate::days::Answer;
+use crate::util::input::year_dir;
+
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct Answers {
+    pub puzzle1: Option<String>,
+    pub puzzle2: Option<String>,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Error(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => write!(f, "FAIL (expected {}, got {})", expected, actual),
+            Verdict::Missing(actual) => write!(f, "missing (got {})", actual),
+            Verdict::Error(err) => write!(f, "ERROR ({})", err),
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, puzzle: usize) -> Option<&String> {
+        match puzzle {
+            1 => self.puzzle1.as_ref(),
+            2 => self.puzzle2.as_ref(),
+            _ => None
+        }
+    }
+
+    pub fn set(&mut self, puzzle: usize, answer: String) {
+        match puzzle {
+            1 => self.puzzle1 = Some(answer),
+            2 => self.puzzle2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    pub fn verify(&self, puzzle: usize, result: &Result<Answer, String>) -> Verdict {
+        let actual = match result {
+            Ok(answer) => answer.to_string(),
+            Err(err) => return Verdict::Error(err.clone()),
+        };
+
+        match self.get(puzzle) {
+            Some(expected) if *expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.clone(), actual },
+            None => Verdict::Missing(actual),
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Unknown answers are written as blank entries, ready to be filled in.
+        for puzzle in [1, 2] {
+            match self.get(puzzle) {
+                Some(answer) => writeln!(f, "{}: {}", puzzle, answer)?,
+                None => writeln!(f, "{}:", puzzle)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Every line holds a single recorded answer as `<puzzle>: <answer>`, e.g. `1: 1234`; a blank
+        // answer (`2:`) is not known yet.
+        let mut answers = Answers::default();
+
+        for line in s.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            let Some((puzzle, answer)) = line.split_once(':') else {
+                return Err(format!("Expected '<puzzle>: <answer>', got '{}'", line));
+            };
+            let answer = match answer.trim() {
+                "" => None,
+                answer => Some(answer.to_string()),
+            };
+
+            match puzzle.trim() {
+                "1" => answers.puzzle1 = answer,
+                "2" => answers.puzzle2 = answer,
+                p => return Err(format!("Unknown puzzle '{}' in answers", p)),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+fn answers_dir(year: i32) -> String {
+    format!("{}/answers", year_dir(year))
+}
+
+pub fn answers_path(year: i32, day: i32) -> String {
+    format!("{}/day{:02}.txt", answers_dir(year), day)
+}
+
+pub fn read_answers(year: i32, day: i32) -> Result<Answers, String> {
+    let answers_path = answers_path(year, day);
+
+    if !Path::new(&answers_path).exists() {
+        return Ok(Answers::default());
+    }
+
+    read_to_string(&answers_path)
+        .map_err(|e| format!("Could not read answers for day {}: {}", day, e))?
+        .parse()
+}
+
+pub fn save_answers(year: i32, day: i32, answers: &Answers) -> Result<(), String> {
+    let dir = answers_dir(year);
+    create_dir_all(&dir).map_err(|e| format!("Could not create {}: {}", dir, e))?;
+    write(answers_path(year, day), answers.to_string()).map_err(|e| format!("Could not write answers for day {}: {}", day, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::Answer;
+    use crate::util::answers::{Answers, Verdict};
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!("1: 1234\n2: ab,cd\n".parse(), Ok(Answers { puzzle1: Some("1234".to_string()), puzzle2: Some("ab,cd".to_string()) }));
+        assert_eq!("2: 42".parse(), Ok(Answers { puzzle1: None, puzzle2: Some("42".to_string()) }));
+        assert_eq!("".parse(), Ok(Answers::default()));
+        assert_eq!("1:\n2:\n".parse(), Ok(Answers::default()));
+        assert!("3: 42".parse::<Answers>().is_err());
+        assert!("42".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(2, "ab,cd".to_string());
+        assert_eq!(answers.to_string(), "1:\n2: ab,cd\n");
+
+        answers.set(1, "1234".to_string());
+        assert_eq!(answers.to_string().parse(), Ok(answers));
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers: Answers = "1: 1234".parse().unwrap();
+
+        assert_eq!(answers.verify(1, &Ok(Answer::Number(1234))), Verdict::Pass);
+        assert_eq!(answers.verify(1, &Ok(Answer::Number(4321))), Verdict::Fail { expected: "1234".to_string(), actual: "4321".to_string() });
+        assert_eq!(answers.verify(2, &Ok(Answer::Text("abc".to_string()))), Verdict::Missing("abc".to_string()));
+        assert_eq!(answers.verify(1, &Err("Oops".to_string())), Verdict::Error("Oops".to_string()));
+    }
+}
@@ -0,0 +1,276 @@
+use std::env;
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::config::Config;
+use crate::util::puzzle_page::strip_tags;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/fvanderveen/advent-of-code-2024";
+
+/// How the server judged a submitted answer.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Feedback {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Feedback {
+    /// Whether the answer was judged; if not, the same answer can safely be submitted again.
+    pub fn is_judged(&self) -> bool {
+        matches!(self, Feedback::Correct | Feedback::Wrong | Feedback::TooHigh | Feedback::TooLow)
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::Wrong => write!(f, "wrong"),
+            Feedback::TooHigh => write!(f, "too-high"),
+            Feedback::TooLow => write!(f, "too-low"),
+            Feedback::RateLimited => write!(f, "rate-limited"),
+            Feedback::AlreadySolved => write!(f, "already-solved"),
+            Feedback::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Feedback::Correct),
+            "wrong" => Ok(Feedback::Wrong),
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            "rate-limited" => Ok(Feedback::RateLimited),
+            "already-solved" => Ok(Feedback::AlreadySolved),
+            "unknown" => Ok(Feedback::Unknown),
+            _ => Err(format!("Unknown feedback '{}'", s)),
+        }
+    }
+}
+
+/// The response to a submitted answer: the feedback, and the message the server gave with it.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct SubmitResponse {
+    pub feedback: Feedback,
+    pub message: String,
+}
+
+impl SubmitResponse {
+    /// Reads the feedback from the response page. Only the `<article>` holds the message, the
+    /// rest of the page is navigation and such.
+    pub fn from_html(html: &str) -> Self {
+        let article = match (html.find("<article>"), html.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &html[start + "<article>".len()..end],
+            _ => html,
+        };
+
+        // Strip the markup, and collapse whitespace left behind by it.
+        let message = strip_tags(article).split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let feedback = if message.contains("That's the right answer") {
+            Feedback::Correct
+        } else if message.contains("your answer is too high") {
+            Feedback::TooHigh
+        } else if message.contains("your answer is too low") {
+            Feedback::TooLow
+        } else if message.contains("That's not the right answer") {
+            Feedback::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Feedback::RateLimited
+        } else if message.contains("Did you already complete it") {
+            Feedback::AlreadySolved
+        } else {
+            Feedback::Unknown
+        };
+
+        Self { feedback, message }
+    }
+}
+
+/// Talks to Advent of Code, or any server that mimics its endpoints.
+/// Requests are throttled across runs: the time of the last request is kept in a state file, and
+/// a new request waits until at least `min_interval` has passed since then.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    state_path: PathBuf,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: Duration::from_secs(5),
+            state_path: Config::get().resources_dir.join(".cache/last_request"),
+        }
+    }
+
+    /// Creates a client from the environment: the session token is taken from `AOC_SESSION`, or
+    /// from the session file (`.aoc_session` unless configured otherwise); `AOC_BASE_URL` overrides
+    /// the Advent of Code url.
+    pub fn from_env() -> Result<Self, String> {
+        let session_file = &Config::get().session_file;
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => read_to_string(session_file)
+                .map_err(|_| format!("No session token found; set AOC_SESSION or put it in {}", session_file.display()))?,
+        };
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn fetch_input(&self, year: i32, day: i32) -> Result<String, String> {
+        self.throttle()?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let mut response = ureq::get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| format!("Could not fetch input for day {}: {}", day, e))?;
+
+        response.body_mut().read_to_string().map_err(|e| format!("Could not read input for day {}: {}", day, e))
+    }
+
+    pub fn submit_answer(&self, year: i32, day: i32, puzzle: usize, answer: &str) -> Result<SubmitResponse, String> {
+        self.throttle()?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let mut response = ureq::post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", puzzle.to_string().as_str()), ("answer", answer)])
+            .map_err(|e| format!("Could not submit answer for day {}: {}", day, e))?;
+
+        let html = response.body_mut().read_to_string().map_err(|e| format!("Could not read response for day {}: {}", day, e))?;
+        Ok(SubmitResponse::from_html(&html))
+    }
+
+    fn throttle(&self) -> Result<(), String> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|e| e.to_string())?;
+
+        let last_request = read_to_string(&self.state_path).ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last_request.and_then(|last| (last + self.min_interval).checked_sub(now)) {
+            eprintln!("Waiting {:.1?} before sending another request...", wait);
+            sleep(wait);
+        }
+
+        if let Some(dir) = self.state_path.parent() {
+            create_dir_all(dir).map_err(|e| format!("Could not create {:?}: {}", dir, e))?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|e| e.to_string())?;
+        write(&self.state_path, now.as_millis().to_string()).map_err(|e| format!("Could not write {:?}: {}", self.state_path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+    use crate::util::aoc_client::{AocClient, Feedback, SubmitResponse};
+
+    /// Serves a single request with the given status and body, returning the request head and body.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<(Vec<String>, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let head: Vec<String> = (&mut reader).lines()
+                .map(|l| l.unwrap())
+                .take_while(|l| !l.is_empty())
+                .collect();
+            let length = head.iter()
+                .find_map(|h| h.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            (head, String::from_utf8(request_body).unwrap())
+        });
+
+        (base_url, handle)
+    }
+
+    fn test_client(base_url: &str, name: &str) -> AocClient {
+        let mut client = AocClient::new(base_url, "secret\n");
+        client.min_interval = Duration::ZERO;
+        client.state_path = temp_dir().join(format!("aoc-client-test-{}-{}", name, std::process::id()));
+        client
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "1 2\n3 4\n");
+
+        let input = test_client(&base_url, "fetch").fetch_input(2024, 5);
+        assert_eq!(input, Ok("1 2\n3 4\n".to_string()));
+
+        let (head, _) = server.join().unwrap();
+        assert_eq!(head[0], "GET /2024/day/5/input HTTP/1.1");
+        assert!(head.iter().any(|h| h.eq_ignore_ascii_case("cookie: session=secret")));
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (base_url, server) = serve_once("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!");
+
+        let input = test_client(&base_url, "error").fetch_input(2024, 25);
+        assert!(input.is_err());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve_once("200 OK", "<html><main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main></html>");
+
+        let response = test_client(&base_url, "submit").submit_answer(2024, 5, 2, "1234");
+        assert_eq!(response, Ok(SubmitResponse { feedback: Feedback::Correct, message: "That's the right answer! You are one gold star closer.".to_string() }));
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!(head[0], "POST /2024/day/5/answer HTTP/1.1");
+        assert_eq!(body, "level=2&answer=1234");
+    }
+
+    #[test]
+    fn test_submit_response_from_html() {
+        let feedback = |html: &str| SubmitResponse::from_html(html).feedback;
+
+        assert_eq!(feedback("<article><p>That's not the right answer; your answer is too high.</p></article>"), Feedback::TooHigh);
+        assert_eq!(feedback("<article><p>That's not the right answer; your answer is too low.</p></article>"), Feedback::TooLow);
+        assert_eq!(feedback("<article><p>That's not the right answer.  If you're stuck, ...</p></article>"), Feedback::Wrong);
+        assert_eq!(feedback("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p></article>"), Feedback::RateLimited);
+        assert_eq!(feedback("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"), Feedback::AlreadySolved);
+        assert_eq!(feedback("<html>Something else</html>"), Feedback::Unknown);
+    }
+
+    #[test]
+    fn test_feedback_round_trip() {
+        for feedback in [Feedback::Correct, Feedback::Wrong, Feedback::TooHigh, Feedback::TooLow, Feedback::RateLimited, Feedback::AlreadySolved, Feedback::Unknown] {
+            assert_eq!(feedback.to_string().parse(), Ok(feedback));
+        }
+    }
+}
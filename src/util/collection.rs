@@ -21,11 +21,11 @@ impl<T> CollectionExtension<T> for Vec<T> where T: Clone + Eq {
     }
 
     fn union(&self, other: &Self) -> Self {
-        self.iter().cloned().filter(|v| other.contains(v)).collect()
+        self.iter().filter(|&v| other.contains(v)).cloned().collect()
     }
 
     fn except(&self, item: &T) -> Self {
-        self.iter().cloned().filter(|v| v.ne(item)).collect()
+        self.iter().filter(|&v| v.ne(item)).cloned().collect()
     }
 
     fn prepend_item(&self, item: &T) -> Self {
@@ -33,7 +33,7 @@ impl<T> CollectionExtension<T> for Vec<T> where T: Clone + Eq {
     }
 
     fn append_item(&self, item: &T) -> Self {
-        self.iter().cloned().chain(vec![item.clone()].into_iter()).collect()
+        self.iter().cloned().chain(vec![item.clone()]).collect()
     }
 
     fn push_all(&mut self, other: &Self) {
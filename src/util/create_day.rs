@@ -1,79 +1,253 @@
+use crate::config::Config;
 use crate::days::get_day;
-use std::fs::{read_to_string, write,};
-use std::path::{Path};
-use std::env::{current_dir};
-use regex::{Captures, RegexBuilder};
+use crate::util::aoc_client::AocClient;
+use crate::util::answers::{answers_path, read_answers, Answers};
+use crate::util::file_transaction::FileTransaction;
+use crate::util::input::{example_input_path, has_puzzle_input, puzzle_input_path, InputError, InputSource, PLACEHOLDER};
+use crate::util::puzzle_page::PuzzlePage;
+use std::fs::{read_dir, read_to_string, remove_dir};
+use std::path::PathBuf;
 use handlebars::{Handlebars};
+use regex::RegexBuilder;
 use serde_json::{json};
 
-pub fn create_day(day: i32) -> Result<(), String> {
-    match get_day(day) {
+/// Creates the source file, input, example input and answers entry of a new day. There is no wiring
+/// to do: `build.rs` registers every day file it finds. Either all files are written, or none.
+/// The day is rendered from `<templates_dir>/<template>.rs.hbs`; a saved puzzle description
+/// (`html`) provides the title and example input when given. A puzzle input we already have is kept.
+pub fn create_day(year: i32, day: i32, template: &str, html: Option<&str>) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {}, puzzles run from day 1 to 25.", day));
+    }
+
+    match get_day(year, day) {
         Err(_) => {
-            let main_dir = match current_dir() {
-                Ok(dir) => dir,
-                Err(e) => { return Err(format!("Could not get working directory: {}", e)); }
-            };
-            let source_file_name = format!("src/days/day{:02}.rs", day);
-            let source_path = main_dir.join(Path::new(&source_file_name));
-            let template_file_name = format!("resources/day{:02}.txt", day);
-            let input_path = main_dir.join(Path::new(&template_file_name));
-            let module_file_name = "src/days.rs".to_string();
-            let module_path = main_dir.join(Path::new(&module_file_name));
+            let source_path = source_path(year, day);
+            let input_path = PathBuf::from(puzzle_input_path(year, day));
+            let example_path = PathBuf::from(example_input_path(year, day, 1));
+            let answers_path = PathBuf::from(answers_path(year, day));
 
             if source_path.exists() {
                 return Err(format!("Source file for day {} already exists.", day));
             }
-            if input_path.exists() {
-                return Err(format!("Input file for day {} already exists.", day));
-            }
 
-            let template = match read_to_string(main_dir.join("resources/day.rs.hbs")) {
-                Ok(v) => { v }
-                Err(e) => { return Err(format!("Could not read day template: {}", e)); }
-            };
-            let days_mod_content = match read_to_string(&module_path) {
-                Ok(v) => { v }
-                Err(e) => { return Err(format!("Could not read days module file: {}", e)); }
+            let page = match html {
+                Some(path) => match read_to_string(path) {
+                    Ok(v) => { PuzzlePage::from_html(&v)? }
+                    Err(e) => { return Err(format!("Could not read puzzle description {}: {}", path, e)); }
+                },
+                None => PuzzlePage::default(),
             };
+            let example = page.example.clone().unwrap_or(PLACEHOLDER.to_string());
+            let day_content = render_day(template, year, day, page.title.as_deref(), &example)?;
 
-            let import_regex = match RegexBuilder::new("^(\\s*)(// « add day import »)").multi_line(true).build() {
-                Ok(r) => { r }
-                Err(e) => { return Err(format!("{}", e)); }
-            };
-            let match_regex = match RegexBuilder::new("^(\\s*)(// « add day match »)").multi_line(true).build() {
-                Ok(r) => { r }
-                Err(e) => { return Err(format!("{}", e)); }
-            };
+            let mut transaction = FileTransaction::new();
+            transaction.write(&source_path, day_content);
 
-            if !import_regex.is_match(days_mod_content.as_str()) {
-                println!("{}", days_mod_content);
-                return Err("Could not find import comment in days module".to_string());
+            if has_puzzle_input(year, day)? {
+                println!("Keeping the existing input for day {}", day);
+            } else {
+                // Try to get the actual input right away; the puzzle might not be unlocked yet though.
+                let input = match AocClient::from_env().and_then(|client| client.fetch_input(year, day)) {
+                    Ok(input) => input,
+                    Err(e) => {
+                        println!("Could not fetch input, writing a placeholder instead: {}", e);
+                        PLACEHOLDER.to_string()
+                    }
+                };
+                transaction.write(&input_path, input);
+            }
+            if !example_path.exists() {
+                transaction.write(&example_path, example);
             }
-            if !match_regex.is_match(days_mod_content.as_str()) {
-                return Err("Could not find match comment in days module".to_string());
+            // Leave any answers we already have alone, otherwise put down blank entries to fill in.
+            if !answers_path.exists() {
+                transaction.write(&answers_path, Answers::default().to_string());
             }
 
-            let res1 = import_regex.replace(days_mod_content.as_str(), |caps: &Captures| {
-                format!("{ws}mod day{day:02};\n{ws}use day{day:02}::DAY{day};\n{ws}{comm}", ws = &caps[1], comm = &caps[2], day = day)
-            });
-            let module_content = match_regex.replace(res1.as_ref(), |caps: &Captures| {
-                format!("{ws}{day} => Ok(DAY{day}),\n{ws}{comm}", ws = &caps[1], comm = &caps[2], day = day)
-            });
-
-            let handlebars = Handlebars::new();
-            let day_content = match handlebars.render_template(template.as_str(), &json!({ "day": day })) {
-                Ok(v) => { v }
-                Err(e) => { return Err(format!("{}", e)); }
+            transaction.commit()
+        }
+        Ok(_) => {
+            Err(format!("Day {} of {} already exists!", day, year))
+        }
+    }
+}
+
+/// Removes a day that was scaffolded by `create_day`, but not worked on yet: its source must still
+/// be exactly what one of the templates rendered. Placeholder inputs and blank answers go with it;
+/// a real puzzle input, example or answer is kept.
+pub fn remove_day(year: i32, day: i32) -> Result<(), String> {
+    let source_path = source_path(year, day);
+
+    let source = match read_to_string(&source_path) {
+        Ok(v) => { v }
+        Err(e) => { return Err(format!("Could not read day file: {:?}\nError: {}", source_path, e)); }
+    };
+    if !is_template_only(year, day, &source)? {
+        return Err(format!("Day {} of {} has changed since it was added, not removing it.", day, year));
+    }
+
+    let mut transaction = FileTransaction::new();
+    transaction.remove(&source_path);
+
+    let input_path = PathBuf::from(puzzle_input_path(year, day));
+    if input_path.exists() && !has_puzzle_input(year, day)? {
+        transaction.remove(&input_path);
+    }
+    let example_path = PathBuf::from(example_input_path(year, day, 1));
+    if let Err(InputError::Empty(_) | InputError::Placeholder(_)) = InputSource::Example(1).read(year, day) {
+        transaction.remove(&example_path);
+    }
+    let answers_path = PathBuf::from(answers_path(year, day));
+    if answers_path.exists() && read_answers(year, day)? == Answers::default() {
+        transaction.remove(&answers_path);
+    }
+
+    transaction.commit()?;
+
+    // Without any days left, the year's directory goes as well; remove_dir refuses when it is not empty.
+    if let Some(dir) = source_path.parent() {
+        let _ = remove_dir(dir);
+    }
+    Ok(())
+}
+
+fn source_path(year: i32, day: i32) -> PathBuf {
+    Config::get().days_dir(year).join(format!("day{:02}.rs", day))
+}
+
+fn render_day(template: &str, year: i32, day: i32, title: Option<&str>, example: &str) -> Result<String, String> {
+    let template_path = Config::get().templates_dir.join(format!("{}.rs.hbs", template));
+    let template = match read_to_string(&template_path) {
+        Ok(v) => { v }
+        Err(e) => { return Err(format!("Could not read template {:?}: {}", template_path, e)); }
+    };
+
+    let handlebars = Handlebars::new();
+    let data = json!({
+        "day": day,
+        "year": year,
+        "module": format!("day{:02}", day),
+        "title": title,
+        "example": example_literal(example),
+    });
+    handlebars.render_template(template.as_str(), &data).map_err(|e| format!("{}", e))
+}
+
+/// Whether the source is what one of the templates renders for the day. The title and example
+/// are taken from the source and example file, as `create_day` put them there.
+fn is_template_only(year: i32, day: i32, source: &str) -> Result<bool, String> {
+    let title_regex = RegexBuilder::new(&format!("^/// (.*)\npub struct Day{};", day)).multi_line(true).build().map_err(|e| e.to_string())?;
+    let title = title_regex.captures(source).map(|caps| caps[1].to_string());
+    let example = match InputSource::Example(1).read(year, day) {
+        Ok(example) => example,
+        Err(_) => PLACEHOLDER.to_string(),
+    };
+
+    let templates_dir = &Config::get().templates_dir;
+    let entries = read_dir(templates_dir).map_err(|e| format!("Could not read templates {:?}: {}", templates_dir, e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let Some(template) = path.file_name().and_then(|n| n.to_str()).and_then(|n| n.strip_suffix(".rs.hbs")) else {
+            continue;
+        };
+
+        if render_day(template, year, day, title.as_deref(), &example)? == source {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Formats the example as the lines of a `"\` string literal, as the day tests have their input.
+fn example_literal(example: &str) -> String {
+    example.lines()
+        .map(|line| {
+            let escaped = line.replace('\\', "\\\\").replace('"', "\\\"");
+            // The literal skips the whitespace that starts a line, so escape the first of it.
+            let escaped = match escaped.strip_prefix(' ') {
+                Some(rest) => format!("\\x20{}", rest),
+                None => escaped,
             };
+            format!("        {}\\n\\", escaped)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-            match write(&input_path, "TODO: Add Content Here") { Err(e) => { return Err(format!("Could not write input file: {:?}\nError: {}", input_path, e)); }, _ => {} }
-            match write(&source_path, day_content) { Err(e) => { return Err(format!("Could not write day file: {:?}\nError: {}", source_path, e)); }, _ => {} }
-            match write(&module_path, module_content.to_string()) { Err(e) => { return Err(format!("Could not write module file: {:?}\nError: {}", module_path, e)); }, _ => {} }
+#[cfg(test)]
+mod tests {
+    use std::fs::{copy, create_dir_all, read_dir, write};
+    use std::path::Path;
+    use std::process::Command;
+    use crate::util::create_day::{create_day, example_literal, is_template_only, render_day};
+    use crate::util::input::PLACEHOLDER;
 
-            Ok(())
+    #[test]
+    fn test_example_literal() {
+        assert_eq!(example_literal("1 2\n\n3 4"), "        1 2\\n\\\n        \\n\\\n        3 4\\n\\");
+        assert_eq!(example_literal("  \"a\\b\""), "        \\x20 \\\"a\\\\b\\\"\\n\\");
+    }
+
+    #[test]
+    fn test_create_day_out_of_range() {
+        assert_eq!(create_day(2024, 0, "default", None), Err("There is no day 0, puzzles run from day 1 to 25.".to_string()));
+        assert_eq!(create_day(2024, 26, "default", None), Err("There is no day 26, puzzles run from day 1 to 25.".to_string()));
+    }
+
+    #[test]
+    fn test_title_is_not_escaped() {
+        // A year without examples, so the placeholder is what the templates get.
+        let title = "Day 3: Mull It Over & Don't";
+        for template in ["default", "grid", "parser", "graph"] {
+            let source = render_day(template, 1999, 3, Some(title), PLACEHOLDER).unwrap();
+            assert!(source.contains("/// Day 3: Mull It Over & Don't\npub struct Day3;"), "{}", template);
+            assert_eq!(is_template_only(1999, 3, &source), Ok(true));
         }
-        Ok(_) => {
-            Err(format!("Day {} already exists!", day))
+    }
+
+    /// Copies the crate to a scratch directory with every template rendered as a day of its own,
+    /// and lints that: a freshly added day has to pass the same checks as the rest of the code.
+    /// It runs a whole clippy build, so it only runs on request: `cargo test -- --ignored templates`.
+    #[test]
+    #[ignore]
+    fn test_templates_pass_clippy() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let scratch = root.join("target/template-check");
+        let crate_dir = scratch.join("crate");
+        copy_dir(&root.join("src"), &crate_dir.join("src"));
+        // Cargo.lock is not checked in, but when there is one it keeps the dependencies the same.
+        for file in ["build.rs", "Cargo.toml", "Cargo.lock"].into_iter().filter(|f| root.join(f).exists()) {
+            copy(root.join(file), crate_dir.join(file)).unwrap();
+        }
+
+        let days_dir = crate_dir.join("src/days/y2099");
+        create_dir_all(&days_dir).unwrap();
+        for (day, template) in ["default", "grid", "parser", "graph"].into_iter().enumerate() {
+            let source = render_day(template, 2099, day as i32 + 1, Some("Day 1: Template Check"), "1\n2").unwrap();
+            write(days_dir.join(format!("day{:02}.rs", day + 1)), source).unwrap();
+        }
+
+        let output = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()))
+            .args(["clippy", "--offline", "--all-targets", "--quiet", "--", "-D", "warnings"])
+            .current_dir(&crate_dir)
+            .env("CARGO_TARGET_DIR", scratch.join("target"))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "Rendered templates do not pass clippy:\n{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        create_dir_all(to).unwrap();
+        for entry in read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                copy(&path, &target).unwrap();
+            }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use crate::util::geometry::{Bounds, Directions, Point};
+
+/// Union-find over the numbers `0..len`: keeps track of which of them are joined together into
+/// sets, in close to constant time per operation.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+}
+
+impl DisjointSet {
+    /// Every element starts out in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self { parents: (0..len).collect(), ranks: vec![0; len] }
+    }
+
+    /// The element representing the set the element is in.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Point everything on the way straight at the root, so the next find is quicker.
+        let mut current = element;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Joins the sets of both elements. Returns whether they were in different sets.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // Hang the shallower tree under the deeper one, to keep the trees flat.
+        let (root, child) = if self.ranks[a] < self.ranks[b] { (b, a) } else { (a, b) };
+        self.parents[child] = root;
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The elements of every set, ordered by their smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![];
+        let mut group_of_root = HashMap::new();
+
+        for element in 0..self.parents.len() {
+            let root = self.find(element);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[group].push(element);
+        }
+
+        groups
+    }
+}
+
+/// A disjoint set of the points within bounds.
+#[derive(Clone, Debug)]
+pub struct PointSets {
+    bounds: Bounds,
+    sets: DisjointSet,
+}
+
+impl PointSets {
+    pub fn new(bounds: Bounds) -> Self {
+        Self { bounds, sets: DisjointSet::new(bounds.width * bounds.height) }
+    }
+
+    fn index(&self, p: &Point) -> Option<usize> {
+        if !self.bounds.contains(p) {
+            return None;
+        }
+        Some((p.y - self.bounds.top) as usize * self.bounds.width + (p.x - self.bounds.left) as usize)
+    }
+
+    fn point(&self, index: usize) -> Point {
+        Point { x: self.bounds.left + (index % self.bounds.width) as isize, y: self.bounds.top + (index / self.bounds.width) as isize }
+    }
+
+    /// Joins the sets of both points. Returns whether they were in different sets; points outside
+    /// of the bounds are never joined.
+    pub fn union(&mut self, a: &Point, b: &Point) -> bool {
+        match (self.index(a), self.index(b)) {
+            (Some(a), Some(b)) => self.sets.union(a, b),
+            _ => false,
+        }
+    }
+
+    pub fn same(&mut self, a: &Point, b: &Point) -> bool {
+        match (self.index(a), self.index(b)) {
+            (Some(a), Some(b)) => self.sets.same(a, b),
+            _ => false,
+        }
+    }
+
+    /// The points of every set, row by row.
+    pub fn groups(&mut self) -> Vec<Vec<Point>> {
+        self.sets.groups().into_iter().map(|group| group.into_iter().map(|i| self.point(i)).collect()).collect()
+    }
+}
+
+/// Groups the points within the bounds into regions: points next to each other (not diagonally)
+/// end up in the same region when they are connected.
+pub fn regions<F>(bounds: Bounds, connected: F) -> Vec<Vec<Point>> where F: Fn(&Point, &Point) -> bool {
+    let mut sets = PointSets::new(bounds);
+    for p in bounds.iter_points() {
+        // Looking right and down is enough, the points to the left and up have looked this way.
+        for direction in [Directions::Right, Directions::Bottom] {
+            let next = p.translate_in_direction(&direction, 1);
+            if bounds.contains(&next) && connected(&p, &next) {
+                sets.union(&p, &next);
+            }
+        }
+    }
+
+    sets.groups()
+}
+
+/// Of the cells that get blocked one after another, finds the first one that cuts `from` off from
+/// `to`; as an index into `blocked`. Before being blocked, cells are open if `passable` says so.
+/// Gives `None` when they stay connected, or never were.
+///
+/// Instead of searching for a way after every cell, this starts with all cells blocked and opens
+/// them up again in reverse: the cell that connects both points is the one that cut them off.
+pub fn first_blocking<F>(bounds: Bounds, passable: F, blocked: &[Point], from: Point, to: Point) -> Option<usize> where F: Fn(&Point) -> bool {
+    // A cell blocked more than once is open until the first time.
+    let mut first_blocked = HashMap::new();
+    for (i, p) in blocked.iter().enumerate() {
+        first_blocked.entry(*p).or_insert(i);
+    }
+
+    let mut sets = PointSets::new(bounds);
+    let mut open = vec![false; bounds.width * bounds.height];
+    let connected = |sets: &mut PointSets, open: &[bool]| {
+        [from, to].iter().all(|p| sets.index(p).is_some_and(|i| open[i])) && sets.same(&from, &to)
+    };
+    let open_up = |sets: &mut PointSets, open: &mut [bool], p: &Point| {
+        let Some(index) = sets.index(p) else { return };
+        open[index] = true;
+        for next in p.neighbors(Directions::NonDiagonal) {
+            if sets.index(&next).is_some_and(|i| open[i]) {
+                sets.union(p, &next);
+            }
+        }
+    };
+
+    for p in bounds.iter_points().filter(|p| passable(p) && !first_blocked.contains_key(p)) {
+        open_up(&mut sets, &mut open, &p);
+    }
+    if connected(&mut sets, &open) {
+        return None;
+    }
+
+    for (i, p) in blocked.iter().enumerate().rev() {
+        if first_blocked[p] != i || !passable(p) {
+            continue;
+        }
+
+        open_up(&mut sets, &mut open, p);
+        if connected(&mut sets, &open) {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::disjoint_set::{first_blocking, regions, DisjointSet, PointSets};
+    use crate::util::geometry::{Bounds, Grid, Point};
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut sets = DisjointSet::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+        assert_eq!(sets.groups(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_point_sets() {
+        let mut sets = PointSets::new(Bounds::from_tlbr(-1, -1, 1, 1));
+        assert!(sets.union(&(-1, -1).into(), &(1, 1).into()));
+        assert!(!sets.union(&(0, 0).into(), &(2, 2).into()));
+
+        assert!(sets.same(&(1, 1).into(), &(-1, -1).into()));
+        assert!(!sets.same(&(2, 2).into(), &(2, 2).into()));
+        assert_eq!(sets.groups()[0], vec![Point::from((-1, -1)), (1, 1).into()]);
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid: Grid<char> = "AAB\nABB\nCAB".parse().unwrap();
+        let regions = regions(grid.bounds, |a, b| grid.get(a) == grid.get(b));
+
+        assert_eq!(regions, vec![
+            vec![(0, 0).into(), (1, 0).into(), (0, 1).into()],
+            vec![(2, 0).into(), (1, 1).into(), (2, 1).into(), (2, 2).into()],
+            vec![(0, 2).into()],
+            vec![Point::from((1, 2))],
+        ]);
+    }
+
+    #[test]
+    fn test_first_blocking() {
+        // A 3 by 3 room with a wall sticking out of the middle of the top.
+        let bounds = Bounds::from_size(3, 3);
+        let passable = |p: &Point| *p != (1, 0).into();
+        let (from, to) = (Point::from((0, 0)), Point::from((2, 0)));
+
+        assert_eq!(first_blocking(bounds, passable, &[(0, 2).into(), (1, 1).into(), (1, 2).into()], from, to), Some(1));
+        assert_eq!(first_blocking(bounds, passable, &[(1, 1).into(), (1, 1).into(), (1, 2).into()], from, to), Some(2));
+        assert_eq!(first_blocking(bounds, passable, &[(1, 0).into(), (1, 0).into()], from, to), None);
+        assert_eq!(first_blocking(bounds, |p| p.x != 1, &[(0, 1).into()], from, to), None);
+        assert_eq!(first_blocking(bounds, passable, &[(2, 0).into()], from, to), Some(0));
+    }
+}
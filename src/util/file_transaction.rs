@@ -0,0 +1,158 @@
+use std::fs::{create_dir, read, remove_dir, remove_file, write};
+use std::path::{Path, PathBuf};
+
+enum Change {
+    Write(PathBuf, Vec<u8>),
+    Remove(PathBuf),
+}
+
+/// What it takes to undo an applied change: the previous content of the file (if any), and the
+/// directories that were created for it.
+struct Undo {
+    path: PathBuf,
+    previous: Option<Vec<u8>>,
+    created_dirs: Vec<PathBuf>,
+}
+
+/// A set of file changes that is applied all-or-nothing: when one of them fails, the ones applied
+/// before it are undone again.
+#[derive(Default)]
+pub struct FileTransaction {
+    changes: Vec<Change>,
+}
+
+impl FileTransaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes the file, creating any missing directories on the way.
+    pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(&mut self, path: P, content: C) {
+        self.changes.push(Change::Write(path.as_ref().to_path_buf(), content.as_ref().to_vec()));
+    }
+
+    pub fn remove<P: AsRef<Path>>(&mut self, path: P) {
+        self.changes.push(Change::Remove(path.as_ref().to_path_buf()));
+    }
+
+    pub fn commit(self) -> Result<(), String> {
+        let mut applied = vec![];
+
+        for change in self.changes {
+            match apply(&change) {
+                Ok(undo) => applied.push(undo),
+                Err(e) => {
+                    let rollback_errors: Vec<_> = applied.iter().rev().filter_map(|undo| revert(undo).err()).collect();
+                    return if rollback_errors.is_empty() {
+                        Err(format!("{} (all changes were rolled back)", e))
+                    } else {
+                        Err(format!("{}; rolling back failed as well: {}", e, rollback_errors.join(", ")))
+                    };
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn apply(change: &Change) -> Result<Undo, String> {
+    match change {
+        Change::Write(path, content) => {
+            let previous = read(path).ok();
+
+            // Create missing directories one by one, so we know which ones to clean up again.
+            let mut created_dirs = vec![];
+            let missing: Vec<_> = path.ancestors().skip(1).filter(|d| !d.as_os_str().is_empty() && !d.exists()).collect();
+            for dir in missing.into_iter().rev() {
+                create_dir(dir).map_err(|e| format!("Could not create directory {:?}: {}", dir, e))?;
+                created_dirs.push(dir.to_path_buf());
+            }
+
+            let undo = Undo { path: path.clone(), previous, created_dirs };
+            match write(path, content) {
+                Ok(_) => Ok(undo),
+                Err(e) => {
+                    // Nothing was written, but the directories we made for it should go again.
+                    let _ = revert(&undo);
+                    Err(format!("Could not write {:?}: {}", path, e))
+                }
+            }
+        }
+        Change::Remove(path) => {
+            let previous = read(path).map_err(|e| format!("Could not read {:?}: {}", path, e))?;
+            remove_file(path).map_err(|e| format!("Could not remove {:?}: {}", path, e))?;
+
+            Ok(Undo { path: path.clone(), previous: Some(previous), created_dirs: vec![] })
+        }
+    }
+}
+
+fn revert(undo: &Undo) -> Result<(), String> {
+    match &undo.previous {
+        Some(content) => write(&undo.path, content).map_err(|e| format!("Could not restore {:?}: {}", undo.path, e))?,
+        None if undo.path.exists() => remove_file(&undo.path).map_err(|e| format!("Could not remove {:?}: {}", undo.path, e))?,
+        None => {}
+    }
+
+    for dir in undo.created_dirs.iter().rev() {
+        remove_dir(dir).map_err(|e| format!("Could not remove directory {:?}: {}", dir, e))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+    use std::path::PathBuf;
+    use crate::util::file_transaction::FileTransaction;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("file-transaction-test-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_commit() {
+        let dir = test_dir("commit");
+        write(dir.join("old.txt"), "old").unwrap();
+        write(dir.join("existing.txt"), "before").unwrap();
+
+        let mut transaction = FileTransaction::new();
+        transaction.write(dir.join("new/nested/file.txt"), "new");
+        transaction.write(dir.join("existing.txt"), "after");
+        transaction.remove(dir.join("old.txt"));
+        assert_eq!(transaction.commit(), Ok(()));
+
+        assert_eq!(read_to_string(dir.join("new/nested/file.txt")).unwrap(), "new");
+        assert_eq!(read_to_string(dir.join("existing.txt")).unwrap(), "after");
+        assert!(!dir.join("old.txt").exists());
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rollback() {
+        let dir = test_dir("rollback");
+        write(dir.join("old.txt"), "old").unwrap();
+        write(dir.join("existing.txt"), "before").unwrap();
+
+        let mut transaction = FileTransaction::new();
+        transaction.write(dir.join("new/nested/file.txt"), "new");
+        transaction.write(dir.join("existing.txt"), "after");
+        transaction.remove(dir.join("old.txt"));
+        // A file can't be written below another file, so this fails.
+        transaction.write(dir.join("existing.txt/file.txt"), "fail");
+        assert!(transaction.commit().unwrap_err().contains("rolled back"));
+
+        assert!(!dir.join("new").exists());
+        assert_eq!(read_to_string(dir.join("existing.txt")).unwrap(), "before");
+        assert_eq!(read_to_string(dir.join("old.txt")).unwrap(), "old");
+
+        remove_dir_all(dir).unwrap();
+    }
+}
@@ -8,6 +8,7 @@ use std::hash::Hash;
 use std::ops::{Add, RangeInclusive, Sub};
 use std::str::FromStr;
 use num_traits::{abs, Zero};
+use crate::util::image::{Color, Image};
 use crate::util::number;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default)]
@@ -16,19 +17,29 @@ pub struct Point {
     pub y: isize,
 }
 
+/// The offsets of the points around a point, clockwise starting at the top left.
+const NEIGHBORS: [(Directions, isize, isize); 8] = [
+    (Directions::TopLeft, -1, -1),
+    (Directions::Top, 0, -1),
+    (Directions::TopRight, 1, -1),
+    (Directions::Right, 1, 0),
+    (Directions::BottomRight, 1, 1),
+    (Directions::Bottom, 0, 1),
+    (Directions::BottomLeft, -1, 1),
+    (Directions::Left, -1, 0),
+];
+
 impl Point {
     pub fn get_points_around(&self, directions: Directions) -> Vec<Point> {
-        let mut points = vec![];
-        if directions.has(Directions::TopLeft) { points.push((self.x - 1, self.y - 1).into()) }
-        if directions.has(Directions::Top) { points.push((self.x, self.y - 1).into()) }
-        if directions.has(Directions::TopRight) { points.push((self.x + 1, self.y - 1).into()) }
-        if directions.has(Directions::Right) { points.push((self.x + 1, self.y).into()) }
-        if directions.has(Directions::BottomRight) { points.push((self.x + 1, self.y + 1).into()) }
-        if directions.has(Directions::Bottom) { points.push((self.x, self.y + 1).into()) }
-        if directions.has(Directions::BottomLeft) { points.push((self.x - 1, self.y + 1).into()) }
-        if directions.has(Directions::Left) { points.push((self.x - 1, self.y).into()) }
+        self.neighbors(directions).collect()
+    }
 
-        return points;
+    /// Like `get_points_around`, without collecting the points.
+    pub fn neighbors(&self, directions: Directions) -> impl Iterator<Item = Point> {
+        let p = *self;
+        NEIGHBORS.into_iter()
+            .filter(move |(direction, _, _)| directions.has(*direction))
+            .map(move |(_, dx, dy)| Point { x: p.x + dx, y: p.y + dy })
     }
 
     pub fn manhattan_distance(&self, other: &Point) -> isize {
@@ -81,10 +92,7 @@ impl FromStr for Point {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts_result: Result<Vec<isize>, String> = s.split(",").map(|p| number::parse_isize(p.trim())).collect();
-        let parts = match parts_result {
-            Ok(v) => v,
-            Err(e) => return Err(e)
-        };
+        let parts = parts_result?;
         match parts.len() {
             2 => Ok((parts[0], parts[1]).into()),
             _ => Err(format!("Invalid str format for Point '{}', expected 'x,y'", s))
@@ -214,6 +222,7 @@ mod point_tests {
     fn test_get_points_around() {
         assert_eq!(Point::from((3, 2)).get_points_around(Directions::NonDiagonal), vec![(3, 1).into(), (4, 2).into(), (3, 3).into(), (2, 2).into()]);
         assert_eq!(Point::from((3, 2)).get_points_around(Directions::Diagonal), vec![(2, 1).into(), (4, 1).into(), (4, 3).into(), (2, 3).into()]);
+        assert_eq!(Point::from((3, 2)).neighbors(Directions::RightAll).collect::<Vec<_>>(), vec![(4, 1).into(), (4, 2).into(), (4, 3).into()]);
     }
 
     #[test]
@@ -305,7 +314,7 @@ impl Point3D {
         let x = (self.x - other.x).abs();
         let y = (self.y - other.y).abs();
         let z = (self.z - other.z).abs();
-        return (x + y + z) as usize;
+        (x + y + z) as usize
     }
 
     pub fn translate(&self, other: &Self) -> Self {
@@ -373,14 +382,14 @@ impl Line {
         let x1 = self.start.x;
         let x2 = self.end.x;
 
-        (x1 - x2).abs() as usize
+        (x1 - x2).unsigned_abs()
     }
 
     fn height(&self) -> usize {
         let y1 = self.start.y;
         let y2 = self.end.y;
 
-        (y1 - y2).abs() as usize
+        (y1 - y2).unsigned_abs()
     }
 
     fn dx(&self) -> isize {
@@ -561,15 +570,40 @@ impl Bounds {
     }
 
     pub fn points(&self) -> Vec<Point> {
-        let mut points = vec![];
+        self.iter_points().collect()
+    }
+
+    /// The points within the bounds, row by row.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point> {
+        let x = self.x();
+        self.y().flat_map(move |y| x.clone().map(move |x| Point { x, y }))
+    }
 
-        for y in self.y() {
-            for x in self.x() {
-                points.push((x, y).into());
+    /// The points from `p` (not included) in a single direction, up to the edge.
+    pub fn points_in_direction(&self, p: &Point, direction: Directions) -> Vec<Point> {
+        match direction {
+            Directions::Top |
+            Directions::Right |
+            Directions::Bottom |
+            Directions::Left |
+            Directions::TopRight |
+            Directions::TopLeft |
+            Directions::BottomRight |
+            Directions::BottomLeft => {
+                let mut points = vec![];
+                let mut current = *p;
+                loop {
+                    let next: Vec<_> = current.get_points_around(direction).into_iter().filter(|p| self.contains(p)).collect();
+                    if next.len() != 1 {
+                        break;
+                    }
+                    current = next[0];
+                    points.push(current);
+                }
+                points
             }
+            _ => vec![]
         }
-
-        points
     }
 }
 
@@ -589,6 +623,7 @@ impl<T> Default for Grid<T> where T: Clone + Default {
 }
 
 #[repr(u8)]
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
 pub enum Directions {
     Top = 1,
@@ -614,7 +649,7 @@ pub enum Directions {
 
 impl Directions {
     pub fn has(&self, value: Directions) -> bool {
-        (self.clone() as u8 & value as u8) != 0
+        (*self as u8 & value as u8) != 0
     }
 }
 
@@ -641,7 +676,11 @@ impl<T> Grid<T> where T: Clone {
     }
 
     pub fn get(&self, p: &Point) -> Option<T> {
-        self.cells.get(p).map(|x| x.clone())
+        self.cells.get(p).cloned()
+    }
+
+    pub fn get_ref(&self, p: &Point) -> Option<&T> {
+        self.cells.get(p)
     }
 
     pub fn has(&self, p: &Point) -> bool {
@@ -704,41 +743,11 @@ impl<T> Grid<T> where T: Clone {
     }
 
     pub fn get_points_in_direction(&self, p: &Point, direction: Directions) -> Vec<Point> {
-        match direction {
-            Directions::Top |
-            Directions::Right |
-            Directions::Bottom |
-            Directions::Left |
-            Directions::TopRight |
-            Directions::TopLeft |
-            Directions::BottomRight |
-            Directions::BottomLeft => {
-                let mut points = vec![];
-                let mut current = p.clone();
-                loop {
-                    let next = self.get_adjacent_points(&current, direction.clone());
-                    if next.len() != 1 {
-                        break;
-                    }
-                    current = next[0];
-                    points.push(current);
-                }
-                points
-            }
-            _ => vec![]
-        }
+        self.bounds.points_in_direction(p, direction)
     }
 
     pub fn points(&self) -> Vec<Point> {
-        let mut points = vec![];
-
-        for y in self.bounds.y() {
-            for x in self.bounds.x() {
-                points.push((x, y).into());
-            }
-        }
-
-        points
+        self.bounds.points()
     }
 
     pub fn values(&self) -> Vec<T> {
@@ -746,7 +755,49 @@ impl<T> Grid<T> where T: Clone {
     }
 
     pub fn entries(&self) -> Vec<(Point, T)> {
-        self.cells.iter().map(|(p, t)| (p.clone(), t.clone())).collect()
+        self.cells.iter().map(|(p, t)| (*p, t.clone())).collect()
+    }
+
+    /// The cells that are set, row by row. This walks all points within the bounds, so for a grid
+    /// with few cells set over a large area, `entries` is faster.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.bounds.iter_points().filter_map(|p| self.cells.get(&p).map(|v| (p, v)))
+    }
+
+    /// The cells that are set, row by row, to change in place.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        // The map can't be walked in bounds order while handing out mutable references, so sort.
+        let mut cells: Vec<_> = self.cells.iter_mut().map(|(p, v)| (*p, v)).collect();
+        cells.sort_unstable_by_key(|(p, _)| *p);
+        cells.into_iter()
+    }
+
+    /// The cells around the point in the directions that are set.
+    pub fn neighbors(&self, p: &Point, directions: Directions) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbors(directions).filter_map(|p| self.cells.get(&p).map(|v| (p, v)))
+    }
+
+    /// The first point, row by row, with a value matching the predicate.
+    pub fn find<F>(&self, predicate: F) -> Option<Point> where F: Fn(&T) -> bool {
+        self.positions(predicate).next()
+    }
+
+    /// The points with a value matching the predicate, row by row.
+    pub fn positions<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = Point> + 'a where F: Fn(&T) -> bool + 'a {
+        self.iter().filter(move |(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    /// Renders the grid with a pixel per cell, colored by the palette. Cells that are not set
+    /// within the bounds get the background color.
+    pub fn to_image<F>(&self, palette: F, background: Color) -> Image where F: Fn(&T) -> Color {
+        let mut image = Image::new(self.bounds.width, self.bounds.height, background);
+        for (p, value) in &self.cells {
+            if self.bounds.contains(p) {
+                image.set((p.x - self.bounds.left) as usize, (p.y - self.bounds.top) as usize, palette(value));
+            }
+        }
+
+        image
     }
 }
 
@@ -775,16 +826,22 @@ impl<T> fmt::Display for Grid<T> where T: fmt::Display + Clone {
             lines.push(line);
         }
 
-        let cell_width = lines.iter().map(|line| line.iter().map(|v| v.chars().count()).max().unwrap_or(0)).max().unwrap_or(0);
+        format_lines(f, &lines)
+    }
+}
+
+/// Writes the cells right aligned in columns of the same width, separated by the fill character
+/// when an alignment is given (as in `{:,>}`).
+fn format_lines(f: &mut fmt::Formatter<'_>, lines: &[Vec<String>]) -> fmt::Result {
+    let cell_width = lines.iter().map(|line| line.iter().map(|v| v.chars().count()).max().unwrap_or(0)).max().unwrap_or(0);
 
-        let fill = f.fill().to_string();
-        let formatted_lines: Vec<_> = lines.iter().map(|line| {
-            let formatted_line: Vec<_> = line.iter().map(|v| " ".repeat(cell_width - v.chars().count()) + v).collect();
-            formatted_line.join(if f.align().is_some() { fill.as_str() } else { "" })
-        }).collect();
+    let fill = f.fill().to_string();
+    let formatted_lines: Vec<_> = lines.iter().map(|line| {
+        let formatted_line: Vec<_> = line.iter().map(|v| " ".repeat(cell_width - v.chars().count()) + v).collect();
+        formatted_line.join(if f.align().is_some() { fill.as_str() } else { "" })
+    }).collect();
 
-        write!(f, "{}", formatted_lines.join("\n"))
-    }
+    write!(f, "{}", formatted_lines.join("\n"))
 }
 
 impl<T> FromStr for Grid<T> where T: FromStr + Clone + Default {
@@ -799,7 +856,7 @@ impl<T> FromStr for Grid<T> where T: FromStr + Clone + Default {
             .collect();
 
         let cells = match parse_result {
-            Ok(lines) if lines.len() == 0 => {
+            Ok(lines) if lines.is_empty() => {
                 return Ok(Grid::default());
             }
             Ok(lines) => lines,
@@ -821,22 +878,253 @@ impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> where T: Clone + Default {
 
         if data.iter().all(|l| l.len() == width) {
             let mut cells = HashMap::new();
-            for y in 0..height {
-                for x in 0..width {
-                    cells.insert((x, y).try_into().unwrap(), data[y][x].clone());
+            for (y, line) in data.iter().enumerate() {
+                for (x, value) in line.iter().enumerate() {
+                    cells.insert((x, y).try_into().unwrap(), value.clone());
                 }
             }
 
             Ok(Grid { bounds, cells })
         } else {
-            Err(format!("Not all lines in input are the same width"))
+            Err("Not all lines in input are the same width".to_string())
+        }
+    }
+}
+
+/// Borrowing access to the cells of either kind of grid, for code that works with both.
+pub trait Cells<T> {
+    fn cell(&self, p: &Point) -> Option<&T>;
+}
+
+impl<T> Cells<T> for Grid<T> where T: Clone {
+    fn cell(&self, p: &Point) -> Option<&T> {
+        self.get_ref(p)
+    }
+}
+
+impl<T> Cells<T> for DenseGrid<T> {
+    fn cell(&self, p: &Point) -> Option<&T> {
+        self.get_ref(p)
+    }
+}
+
+/// A grid with a value for every point within its bounds, stored row by row. Unlike `Grid` it does
+/// not grow, but looking up a point is an index into a `Vec` instead of hashing the point, and the
+/// values can be borrowed instead of cloned.
+#[derive(Eq, PartialEq, Clone)]
+pub struct DenseGrid<T> {
+    pub bounds: Bounds,
+    cells: Vec<T>,
+}
+
+impl<T> Default for DenseGrid<T> {
+    fn default() -> Self {
+        DenseGrid {
+            bounds: Bounds::default(),
+            cells: vec![],
+        }
+    }
+}
+
+#[allow(unused)]
+impl<T> DenseGrid<T> {
+    pub fn new(bounds: Bounds, value: T) -> Self where T: Clone {
+        Self { bounds, cells: vec![value; bounds.width * bounds.height] }
+    }
+
+    pub fn with_size(bounds: Bounds) -> Self where T: Clone + Default {
+        Self::new(bounds, T::default())
+    }
+
+    fn index(&self, p: &Point) -> Option<usize> {
+        if !self.bounds.contains(p) {
+            return None;
+        }
+        Some((p.y - self.bounds.top) as usize * self.bounds.width + (p.x - self.bounds.left) as usize)
+    }
+
+    pub fn get(&self, p: &Point) -> Option<T> where T: Clone {
+        self.get_ref(p).cloned()
+    }
+
+    pub fn get_ref(&self, p: &Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    pub fn has(&self, p: &Point) -> bool {
+        self.bounds.contains(p)
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Panics when the point is outside of the bounds, since a dense grid does not grow.
+    pub fn set(&mut self, p: Point, v: T) {
+        match self.index(&p) {
+            Some(i) => self.cells[i] = v,
+            None => panic!("Point {} is outside of the grid {:?}", p, self.bounds),
+        }
+    }
+
+    /// The values of a row, borrowed; empty for a row outside of the bounds.
+    pub fn row(&self, row: isize) -> &[T] {
+        match self.index(&(self.bounds.left, row).into()) {
+            Some(start) => &self.cells[start..start + self.bounds.width],
+            None => &[],
+        }
+    }
+
+    pub fn get_row(&self, row: isize) -> Vec<T> where T: Clone {
+        self.row(row).to_vec()
+    }
+
+    pub fn rows(&self) -> Vec<Vec<T>> where T: Clone {
+        self.bounds.y().map(|row| self.get_row(row)).collect()
+    }
+
+    pub fn get_column(&self, column: isize) -> Vec<T> where T: Clone {
+        self.bounds.y().filter_map(|y| self.get(&Point::from((column, y)))).collect()
+    }
+
+    pub fn columns(&self) -> Vec<Vec<T>> where T: Clone {
+        self.bounds.x().map(|column| self.get_column(column)).collect()
+    }
+
+    pub fn get_adjacent(&self, p: &Point, directions: Directions) -> Vec<T> where T: Clone {
+        self.get_adjacent_points(p, directions).iter().filter_map(|p| self.get(p)).collect()
+    }
+
+    pub fn get_adjacent_points(&self, p: &Point, directions: Directions) -> Vec<Point> {
+        p.get_points_around(directions).into_iter().filter(|p| self.bounds.contains(p)).collect()
+    }
+
+    pub fn get_adjacent_entries(&self, p: &Point, directions: Directions) -> Vec<(Point, T)> where T: Clone {
+        self.get_adjacent_points(p, directions).into_iter().filter_map(|p| self.get(&p).map(|i| (p, i))).collect()
+    }
+
+    pub fn get_in_direction(&self, p: &Point, direction: Directions) -> Vec<T> where T: Clone {
+        self.get_points_in_direction(p, direction).iter().filter_map(|p| self.get(p)).collect()
+    }
+
+    pub fn get_points_in_direction(&self, p: &Point, direction: Directions) -> Vec<Point> {
+        self.bounds.points_in_direction(p, direction)
+    }
+
+    pub fn points(&self) -> Vec<Point> {
+        self.bounds.points()
+    }
+
+    /// All values, row by row.
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn values(&self) -> Vec<T> where T: Clone {
+        self.cells.clone()
+    }
+
+    pub fn entries(&self) -> Vec<(Point, T)> where T: Clone {
+        self.points().into_iter().zip(self.cells.iter().cloned()).collect()
+    }
+
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.bounds.iter_points().zip(self.cells.iter())
+    }
+
+    /// All cells, row by row, to change in place.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.bounds.iter_points().zip(self.cells.iter_mut())
+    }
+
+    /// The cells around the point in the directions that are within the bounds.
+    pub fn neighbors(&self, p: &Point, directions: Directions) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbors(directions).filter_map(|p| self.get_ref(&p).map(|v| (p, v)))
+    }
+
+    /// The first point, row by row, with a value matching the predicate.
+    pub fn find<F>(&self, predicate: F) -> Option<Point> where F: Fn(&T) -> bool {
+        self.positions(predicate).next()
+    }
+
+    /// The points with a value matching the predicate, row by row.
+    pub fn positions<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = Point> + 'a where F: Fn(&T) -> bool + 'a {
+        self.iter().filter(move |(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    /// Renders the grid with a pixel per cell, colored by the palette. Takes a background like
+    /// `Grid::to_image`, though with a value for every cell it is drawn over completely.
+    pub fn to_image<F>(&self, palette: F, background: Color) -> Image where F: Fn(&T) -> Color {
+        let mut image = Image::new(self.bounds.width, self.bounds.height, background);
+        for (i, value) in self.cells.iter().enumerate() {
+            image.set(i % self.bounds.width, i / self.bounds.width, palette(value));
+        }
+
+        image
+    }
+}
+
+impl<T> fmt::Debug for DenseGrid<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DenseGrid")
+            .field("bounds", &self.bounds)
+            .field("map", &format_args!("{:,>}", &self))
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for DenseGrid<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<Vec<String>> = self.cells.chunks(self.bounds.width.max(1))
+            .map(|row| row.iter().map(|v| v.to_string()).collect())
+            .collect();
+
+        format_lines(f, &lines)
+    }
+}
+
+impl<T> FromStr for DenseGrid<T> where T: FromStr + Clone + Default {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Grid<T>>().map(|grid| DenseGrid::from(&grid))
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for DenseGrid<T> {
+    type Error = String;
+
+    fn try_from(data: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let height = data.len();
+        let width = data.first().map_or(0, |line| line.len());
+
+        if data.iter().all(|l| l.len() == width) {
+            Ok(DenseGrid { bounds: Bounds { top: 0, left: 0, width, height }, cells: data.into_iter().flatten().collect() })
+        } else {
+            Err("Not all lines in input are the same width".to_string())
         }
     }
 }
 
+/// Points of the bounds that are not set in the sparse grid get the default value.
+impl<T> From<&Grid<T>> for DenseGrid<T> where T: Clone + Default {
+    fn from(grid: &Grid<T>) -> Self {
+        let cells = grid.points().iter().map(|p| grid.get(p).unwrap_or_default()).collect();
+        DenseGrid { bounds: grid.bounds, cells }
+    }
+}
+
+impl<T> From<&DenseGrid<T>> for Grid<T> where T: Clone {
+    fn from(grid: &DenseGrid<T>) -> Self {
+        Grid { bounds: grid.bounds, cells: grid.entries().into_iter().collect() }
+    }
+}
+
 #[cfg(test)]
 mod grid_tests {
     use crate::util::geometry::{Grid, Directions, Bounds};
+    use crate::util::image::Color;
 
     const EXAMPLE_GRID_INPUT: &str = "\
         2199943210\n\
@@ -964,4 +1252,126 @@ mod grid_tests {
             (-2, 3).into(), (-1, 3).into(), (0, 3).into(), (1, 3).into(), (2, 3).into(),
         ]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_iter_and_find() {
+        let mut grid = get_example_grid();
+        assert_eq!(grid.get_ref(&(5, 3).into()), Some(&9));
+        assert_eq!(grid.iter().take(3).collect::<Vec<_>>(), vec![((0, 0).into(), &2), ((1, 0).into(), &1), ((2, 0).into(), &9)]);
+        assert_eq!(grid.find(|v| *v == 5), Some((2, 2).into()));
+        assert_eq!(grid.find(|v| *v > 9), None);
+        assert_eq!(grid.positions(|v| *v == 0).collect::<Vec<_>>(), vec![(9, 0).into()]);
+        assert_eq!(grid.neighbors(&(9, 4).into(), Directions::NonDiagonal).collect::<Vec<_>>(), vec![((9, 3).into(), &9), ((8, 4).into(), &7)]);
+
+        for (p, v) in grid.iter_mut() {
+            *v = (p.y * 10 + p.x) as usize;
+        }
+        assert_eq!(grid.get_row(1), vec![10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+
+        // Cells that are not set are skipped.
+        let mut grid: Grid<char> = Grid::default();
+        grid.set((1, 1).into(), 'b');
+        grid.set((0, 0).into(), 'a');
+        assert_eq!(grid.iter().collect::<Vec<_>>(), vec![((0, 0).into(), &'a'), ((1, 1).into(), &'b')]);
+        assert_eq!(grid.iter_mut().map(|(p, _)| p).collect::<Vec<_>>(), vec![(0, 0).into(), (1, 1).into()]);
+        assert_eq!(grid.neighbors(&(1, 0).into(), Directions::All).count(), 2);
+    }
+
+    #[test]
+    fn test_to_image() {
+        let mut grid: Grid<bool> = Grid::default();
+        grid.set((-1, 0).into(), true);
+        grid.set((1, 1).into(), false);
+
+        let image = grid.to_image(|lit| if *lit { Color::WHITE } else { Color::RED }, Color::BLACK);
+        assert_eq!((image.width, image.height), (3, 2));
+        assert_eq!(image.pixels(), [Color::WHITE, Color::BLACK, Color::BLACK, Color::BLACK, Color::BLACK, Color::RED]);
+    }
+}
+
+#[cfg(test)]
+mod dense_grid_tests {
+    use crate::util::geometry::{Bounds, DenseGrid, Directions, Grid};
+    use crate::util::image::Color;
+
+    const EXAMPLE_GRID_INPUT: &str = "\
+        2199943210\n\
+        3987894921\n\
+        9856789892\n\
+        8767896789\n\
+        9899965678\
+    ";
+
+    fn get_example_grid() -> DenseGrid<usize> {
+        EXAMPLE_GRID_INPUT.parse().unwrap()
+    }
+
+    #[test]
+    fn test_dense_grid_from_str() {
+        let grid = get_example_grid();
+        assert_eq!(grid.bounds, Bounds::from_size(10, 5));
+        assert_eq!(format!("{}", grid), EXAMPLE_GRID_INPUT);
+        assert_eq!(grid.rows(), EXAMPLE_GRID_INPUT.parse::<Grid<usize>>().unwrap().rows());
+        assert_eq!(DenseGrid::try_from(vec![vec![2, 1], vec![3, 9]]), Ok("21\n39".parse().unwrap()));
+        assert!(DenseGrid::try_from(vec![vec![2, 1], vec![3]]).is_err());
+        assert!("21\n3".parse::<DenseGrid<usize>>().is_err());
+    }
+
+    #[test]
+    fn test_dense_grid_get_and_set() {
+        let mut grid = get_example_grid();
+        assert_eq!(grid.get(&(5, 3).into()), Some(9));
+        assert_eq!(grid.get_ref(&(9, 4).into()), Some(&8));
+        assert_eq!(grid.get(&(10, 0).into()), None);
+        assert_eq!(grid.get(&(0, -1).into()), None);
+
+        grid.set((5, 3).into(), 0);
+        *grid.get_mut(&(0, 0).into()).unwrap() += 5;
+        assert_eq!(grid.row(3), [8, 7, 6, 7, 8, 0, 6, 7, 8, 9]);
+        assert_eq!(grid.get_column(0), vec![7, 3, 9, 8, 9]);
+        assert!(grid.row(5).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dense_grid_does_not_grow() {
+        get_example_grid().set((10, 0).into(), 1);
+    }
+
+    #[test]
+    fn test_dense_grid_adjacent() {
+        let grid = get_example_grid();
+        assert_eq!(grid.get_adjacent(&(0, 0).into(), Directions::All), vec![1, 9, 3]);
+        assert_eq!(grid.get_adjacent_entries(&(9, 4).into(), Directions::NonDiagonal), vec![((9, 3).into(), 9), ((8, 4).into(), 7)]);
+        assert_eq!(grid.get_in_direction(&(2, 0).into(), Directions::Left), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_dense_grid_iter_and_find() {
+        let mut grid = get_example_grid();
+        assert_eq!(grid.iter().nth(12), Some(((2, 1).into(), &8)));
+        assert_eq!(grid.find(|v| *v == 5), Some((2, 2).into()));
+        assert_eq!(grid.positions(|v| *v == 1).collect::<Vec<_>>(), vec![(1, 0).into(), (8, 0).into(), (9, 1).into()]);
+        assert_eq!(grid.neighbors(&(0, 0).into(), Directions::All).map(|(_, v)| *v).collect::<Vec<_>>(), vec![1, 9, 3]);
+
+        grid.iter_mut().filter(|(p, _)| p.x == 0).for_each(|(_, v)| *v = 0);
+        assert_eq!(grid.get_column(0), vec![0; 5]);
+        assert_eq!(grid.get_column(1), vec![1, 9, 8, 7, 8]);
+    }
+
+    #[test]
+    fn test_dense_grid_offset_bounds() {
+        let mut grid = DenseGrid::new(Bounds::from_tlbr(-1, -2, 0, 0), '.');
+        grid.set((-2, -1).into(), '#');
+        grid.set((0, 0).into(), '@');
+        assert_eq!(format!("{}", grid), "#..\n..@");
+        assert_eq!(grid.entries()[0], ((-2, -1).into(), '#'));
+
+        let sparse = Grid::from(&grid);
+        assert_eq!(sparse.bounds, grid.bounds);
+        assert_eq!(DenseGrid::from(&sparse), grid);
+
+        let image = grid.to_image(|c| if *c == '.' { Color::BLACK } else { Color::WHITE }, Color::RED);
+        assert_eq!(image.pixels(), [Color::WHITE, Color::BLACK, Color::BLACK, Color::BLACK, Color::BLACK, Color::WHITE]);
+    }
+}
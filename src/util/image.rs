@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::fs::{create_dir_all, write, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const RED: Color = Color::rgb(255, 0, 0);
+    pub const GREEN: Color = Color::rgb(0, 255, 0);
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    pub const fn gray(value: u8) -> Self {
+        Self::rgb(value, value, value)
+    }
+
+    /// The perceived brightness, for grayscale images.
+    pub fn luma(&self) -> u8 {
+        ((299 * self.r as u32 + 587 * self.g as u32 + 114 * self.b as u32) / 1000) as u8
+    }
+}
+
+/// The file formats images can be saved in; GIF is the only one holding more than one frame.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum ImageFormat {
+    Ppm,
+    Pgm,
+    Png,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("ppm") => Ok(ImageFormat::Ppm),
+            Some("pgm") => Ok(ImageFormat::Pgm),
+            Some("png") => Ok(ImageFormat::Png),
+            Some("gif") => Ok(ImageFormat::Gif),
+            _ => Err(format!("Unknown image format for {:?}, expected .ppm, .pgm, .png or .gif", path)),
+        }
+    }
+}
+
+/// An RGB image, with its pixels stored row by row.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self { width, height, pixels: vec![background; width * height] }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Color> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Sets a pixel; pixels outside the image are ignored.
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// For tests to compare a whole image at once.
+    #[cfg(test)]
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    /// Blows the image up, every pixel becoming a square of `factor` pixels wide.
+    pub fn scaled(&self, factor: usize) -> Image {
+        if factor <= 1 {
+            return self.clone();
+        }
+
+        let mut image = Image::new(self.width * factor, self.height * factor, Color::BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.pixels[y * image.width + x] = self.pixels[(y / factor) * self.width + x / factor];
+            }
+        }
+        image
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().flat_map(|c| [c.r, c.g, c.b]));
+        data
+    }
+
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut data = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().map(|c| c.luma()));
+        data
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut data = vec![];
+        let mut encoder = png::Encoder::new(&mut data, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(|e| format!("Could not encode PNG: {}", e))?;
+        let rgb: Vec<u8> = self.pixels.iter().flat_map(|c| [c.r, c.g, c.b]).collect();
+        writer.write_image_data(&rgb).map_err(|e| format!("Could not encode PNG: {}", e))?;
+        writer.finish().map_err(|e| format!("Could not encode PNG: {}", e))?;
+
+        Ok(data)
+    }
+
+    pub fn encode(&self, format: ImageFormat) -> Result<Vec<u8>, String> {
+        match format {
+            ImageFormat::Ppm => Ok(self.to_ppm()),
+            ImageFormat::Pgm => Ok(self.to_pgm()),
+            ImageFormat::Png => self.to_png(),
+            ImageFormat::Gif => encode_gif(std::slice::from_ref(self), 0),
+        }
+    }
+
+    /// Draws the image with unicode block characters, fitting 2x2 pixels in one character. Any pixel
+    /// that is not black is drawn.
+    pub fn to_blocks(&self) -> String {
+        let lit = |x: usize, y: usize| self.get(x, y).is_some_and(|c| c != Color::BLACK);
+        let mut blocks = String::new();
+
+        for y in (0..self.height).step_by(2) {
+            for x in (0..self.width).step_by(2) {
+                blocks.push(match (lit(x, y), lit(x + 1, y), lit(x, y + 1), lit(x + 1, y + 1)) {
+                    (true, true, true, true) => '█',
+                    (true, false, false, false) => '▘',
+                    (false, true, false, false) => '▝',
+                    (false, false, true, false) => '▖',
+                    (false, false, false, true) => '▗',
+                    (true, true, false, false) => '▀',
+                    (false, false, true, true) => '▄',
+                    (true, false, true, false) => '▌',
+                    (false, true, false, true) => '▐',
+                    (true, false, false, true) => '▚',
+                    (false, true, true, false) => '▞',
+                    (true, true, true, false) => '▛',
+                    (true, true, false, true) => '▜',
+                    (true, false, true, true) => '▙',
+                    (false, true, true, true) => '▟',
+                    (false, false, false, false) => ' ',
+                });
+            }
+            blocks.push('\n');
+        }
+
+        blocks
+    }
+
+    /// Draws the image in color for terminals with ANSI true color support, fitting two pixels in one
+    /// character: the top one as the foreground of a `▀`, the bottom one as its background.
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let top = self.get(x, y).unwrap_or(Color::BLACK);
+                let bottom = self.get(x, y + 1).unwrap_or(Color::BLACK);
+                ansi.push_str(&format!("\x1b[38;2;{};{};{};48;2;{};{};{}m▀", top.r, top.g, top.b, bottom.r, bottom.g, bottom.b));
+            }
+            ansi.push_str("\x1b[0m\n");
+        }
+
+        ansi
+    }
+}
+
+/// An endlessly looping GIF, written frame by frame so long animations need not fit in memory.
+/// Each frame shows for `delay` hundredths of a second, and must have the size of the animation.
+pub struct GifAnimation<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    delay: u16,
+}
+
+impl<W: Write> GifAnimation<W> {
+    pub fn new(writer: W, width: usize, height: usize, delay: u16) -> Result<Self, String> {
+        let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(format!("Image of {}x{} is too large for a GIF", width, height));
+        };
+
+        let mut encoder = gif::Encoder::new(writer, w, h, &[]).map_err(|e| format!("Could not encode GIF: {}", e))?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(|e| format!("Could not encode GIF: {}", e))?;
+
+        Ok(Self { encoder, width: w, height: h, delay })
+    }
+
+    pub fn add_frame(&mut self, image: &Image) -> Result<(), String> {
+        if (image.width, image.height) != (self.width as usize, self.height as usize) {
+            return Err(format!("Frame of {}x{} does not fit an animation of {}x{}", image.width, image.height, self.width, self.height));
+        }
+
+        let mut frame = gif_frame(image, self.width, self.height);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame).map_err(|e| format!("Could not encode GIF: {}", e))
+    }
+
+    pub fn finish(self) -> Result<W, String> {
+        self.encoder.into_inner().map_err(|e| format!("Could not encode GIF: {}", e))
+    }
+}
+
+/// Encodes the frames as a GIF in memory, see `GifAnimation`.
+pub fn encode_gif(frames: &[Image], delay: u16) -> Result<Vec<u8>, String> {
+    let Some(first) = frames.first() else {
+        return Err("Can not make a GIF without frames".to_string());
+    };
+
+    let mut animation = GifAnimation::new(vec![], first.width, first.height, delay)?;
+    for frame in frames {
+        animation.add_frame(frame)?;
+    }
+    animation.finish()
+}
+
+fn gif_frame(image: &Image, width: u16, height: u16) -> gif::Frame<'static> {
+    // Grid images rarely have many colors, so they usually fit a palette as they are. Only larger
+    // numbers of colors need quantizing.
+    let mut palette: HashMap<Color, u8> = HashMap::new();
+    for color in &image.pixels {
+        if !palette.contains_key(color) {
+            if palette.len() == 256 {
+                let rgb: Vec<u8> = image.pixels.iter().flat_map(|c| [c.r, c.g, c.b]).collect();
+                return gif::Frame::from_rgb_speed(width, height, &rgb, 10);
+            }
+            palette.insert(*color, palette.len() as u8);
+        }
+    }
+
+    let mut colors = vec![Color::BLACK; palette.len()];
+    for (color, index) in &palette {
+        colors[*index as usize] = *color;
+    }
+
+    let indices: Vec<u8> = image.pixels.iter().map(|c| palette[c]).collect();
+    gif::Frame::from_palette_pixels(width, height, indices, colors.iter().flat_map(|c| [c.r, c.g, c.b]).collect::<Vec<_>>(), None)
+}
+
+/// Saves the image in the format matching the extension of the path, creating its directory if needed.
+pub fn save_image<P: AsRef<Path>>(path: P, image: &Image) -> Result<(), String> {
+    let path = path.as_ref();
+    write_file(path, &image.encode(ImageFormat::from_path(path)?)?)
+}
+
+/// Creates a GIF file to add frames to, creating its directory if needed.
+pub fn create_animation<P: AsRef<Path>>(path: P, width: usize, height: usize, delay: u16) -> Result<GifAnimation<BufWriter<File>>, String> {
+    let path = path.as_ref();
+    create_parent_dir(path)?;
+    let file = File::create(path).map_err(|e| format!("Could not create {:?}: {}", path, e))?;
+    GifAnimation::new(BufWriter::new(file), width, height, delay)
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<(), String> {
+    create_parent_dir(path)?;
+    write(path, data).map_err(|e| format!("Could not write {:?}: {}", path, e))
+}
+
+fn create_parent_dir(path: &Path) -> Result<(), String> {
+    match path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        Some(dir) => create_dir_all(dir).map_err(|e| format!("Could not create directory {:?}: {}", dir, e)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::image::{encode_gif, Color, Image, ImageFormat};
+
+    fn checkers() -> Image {
+        let mut image = Image::new(3, 2, Color::BLACK);
+        image.set(0, 0, Color::WHITE);
+        image.set(2, 0, Color::WHITE);
+        image.set(1, 1, Color::RED);
+        image.set(5, 5, Color::RED);
+        image
+    }
+
+    #[test]
+    fn test_pixels() {
+        let image = checkers();
+        assert_eq!(image.get(0, 0), Some(Color::WHITE));
+        assert_eq!(image.get(1, 0), Some(Color::BLACK));
+        assert_eq!(image.get(3, 0), None);
+        assert_eq!(image.to_blocks(), "▚▘\n");
+        assert_eq!(Image::new(1, 1, Color::RED).to_ansi(), "\x1b[38;2;255;0;0;48;2;0;0;0m▀\x1b[0m\n");
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width, scaled.height), (6, 4));
+        assert_eq!(scaled.get(1, 1), Some(Color::WHITE));
+        assert_eq!(scaled.get(3, 3), Some(Color::RED));
+        assert_eq!(scaled.get(5, 3), Some(Color::BLACK));
+    }
+
+    #[test]
+    fn test_netpbm() {
+        let image = checkers();
+        assert_eq!(image.to_ppm(), [b"P6\n3 2\n255\n".as_slice(), &[255, 255, 255, 0, 0, 0, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0]].concat());
+        assert_eq!(image.to_pgm(), [b"P5\n3 2\n255\n".as_slice(), &[255, 0, 255, 0, 76, 0]].concat());
+    }
+
+    #[test]
+    fn test_png_and_gif() {
+        let png = checkers().to_png().unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let gif = encode_gif(&[checkers(), Image::new(3, 2, Color::BLUE)], 10).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert!(encode_gif(&[], 10).is_err());
+        assert!(encode_gif(&[checkers(), Image::new(2, 2, Color::BLUE)], 10).is_err());
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(ImageFormat::from_path("frames/day14.PNG"), Ok(ImageFormat::Png));
+        assert_eq!(ImageFormat::from_path("day14.pgm"), Ok(ImageFormat::Pgm));
+        assert!(ImageFormat::from_path("day14.jpg").is_err());
+        assert!(ImageFormat::from_path("day14").is_err());
+    }
+}
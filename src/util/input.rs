@@ -1,10 +1,146 @@
-use std::fs::{read_to_string, exists};
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, exists, write};
+use std::io::stdin;
+use crate::config::Config;
 
-pub fn read_input(day: i32) -> Result<String, String> {
-    let input_path = format!("resources/day{:02}.txt", day);
+/// The content `add` writes to a new day's input file, until the actual input is pasted in.
+pub const PLACEHOLDER: &str = "TODO: Add Content Here";
 
-    match exists(&input_path) {
-        Ok(_) => read_to_string(&input_path).map_err(|e| format!("{}", e)),
-        Err(_) => Err(format!("Input for day {} not found in resources directory!", day)),
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub enum InputSource {
+    /// The day's puzzle input, `resources/YYYY/dayNN.txt`.
+    #[default]
+    Puzzle,
+    /// An example input stored alongside the day, `resources/YYYY/examples/dayNN_K.txt`.
+    Example(usize),
+    File(String),
+    Stdin,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum InputError {
+    Missing(String),
+    Empty(String),
+    Placeholder(String),
+    Unreadable(String, String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(source) => write!(f, "Input {} does not exist; save the puzzle input there first.", source),
+            InputError::Empty(source) => write!(f, "Input {} is empty.", source),
+            InputError::Placeholder(source) => write!(f, "Input {} still holds the placeholder written by `add`; paste the puzzle input into it.", source),
+            InputError::Unreadable(source, err) => write!(f, "Could not read input {}: {}", source, err),
+        }
+    }
+}
+
+impl From<InputError> for String {
+    fn from(err: InputError) -> Self {
+        err.to_string()
+    }
+}
+
+impl InputSource {
+    pub fn read(&self, year: i32, day: i32) -> Result<String, InputError> {
+        match self {
+            InputSource::Puzzle => read_file(&puzzle_input_path(year, day)),
+            InputSource::Example(example) => read_file(&example_input_path(year, day, *example)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let content = std::io::read_to_string(stdin()).map_err(|e| InputError::Unreadable("stdin".to_string(), e.to_string()))?;
+                check_content("stdin", &content)
+            }
+        }
+    }
+}
+
+/// The directory holding everything specific to a year: inputs, examples, answers and such.
+pub fn year_dir(year: i32) -> String {
+    Config::get().resources_dir.join(year.to_string()).display().to_string()
+}
+
+pub fn puzzle_input_path(year: i32, day: i32) -> String {
+    format!("{}/day{:02}.txt", year_dir(year), day)
+}
+
+pub fn example_input_path(year: i32, day: i32, example: usize) -> String {
+    format!("{}/examples/day{:02}_{}.txt", year_dir(year), day, example)
+}
+
+/// Whether the day has an actual puzzle input; a missing, empty or placeholder input does not count.
+pub fn has_puzzle_input(year: i32, day: i32) -> Result<bool, InputError> {
+    match InputSource::Puzzle.read(year, day) {
+        Ok(_) => Ok(true),
+        Err(InputError::Missing(_) | InputError::Empty(_) | InputError::Placeholder(_)) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Stores a day's puzzle input. An existing input is never overwritten, unless it is empty or
+/// still holds the placeholder.
+pub fn save_input(year: i32, day: i32, content: &str) -> Result<(), String> {
+    if has_puzzle_input(year, day)? {
+        return Err(format!("Input for day {} already exists, refusing to overwrite it.", day));
+    }
+
+    let dir = year_dir(year);
+    create_dir_all(&dir).map_err(|e| format!("Could not create {}: {}", dir, e))?;
+    let path = puzzle_input_path(year, day);
+    write(&path, content).map_err(|e| format!("Could not write input file {}: {}", path, e))
+}
+
+fn read_file(path: &str) -> Result<String, InputError> {
+    match exists(path) {
+        Ok(true) => {},
+        Ok(false) => return Err(InputError::Missing(path.to_string())),
+        Err(e) => return Err(InputError::Unreadable(path.to_string(), e.to_string())),
+    }
+
+    let content = read_to_string(path).map_err(|e| InputError::Unreadable(path.to_string(), e.to_string()))?;
+    check_content(path, &content)
+}
+
+fn check_content(source: &str, content: &str) -> Result<String, InputError> {
+    let normalized = normalize(content);
+
+    if normalized.is_empty() {
+        Err(InputError::Empty(source.to_string()))
+    } else if normalized == PLACEHOLDER {
+        Err(InputError::Placeholder(source.to_string()))
+    } else {
+        Ok(normalized)
+    }
+}
+
+/// Normalizes line endings to `\n` and strips trailing whitespace, both per line and at the end of
+/// the input, so parsers don't have to deal with either.
+fn normalize(content: &str) -> String {
+    content.lines()
+        .map(|l| l.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::input::{check_content, normalize, InputError, PLACEHOLDER};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1 2\r\n3 4\r\n"), "1 2\n3 4");
+        assert_eq!(normalize("1 2  \n\n3 4\t\n\n\n"), "1 2\n\n3 4");
+        assert_eq!(normalize("  indented\n"), "  indented");
+    }
+
+    #[test]
+    fn test_check_content() {
+        assert_eq!(check_content("test", "42\n"), Ok("42".to_string()));
+        assert_eq!(check_content("test", ""), Err(InputError::Empty("test".to_string())));
+        assert_eq!(check_content("test", " \r\n\n"), Err(InputError::Empty("test".to_string())));
+        assert_eq!(check_content("test", PLACEHOLDER), Err(InputError::Placeholder("test".to_string())));
     }
 }
@@ -59,7 +59,7 @@ pub fn gcd<T: Num + Copy>(a: T, b: T) -> T {
         return a;
     }
 
-    return gcd(b, a % b);
+    gcd(b, a % b)
 }
 
 pub trait NumberExtensions<T> {
@@ -69,7 +69,7 @@ pub trait NumberExtensions<T> {
 impl<T> NumberExtensions<T> for Vec<T> where T: Num + Copy + Clone {
     fn lcm(&self) -> T {
         if let Some((first, rest)) = self.split_first() {
-            rest.iter().fold(first.clone(), |acc,v| lcm(acc, v.clone()))
+            rest.iter().fold(*first, |acc,v| lcm(acc, *v))
         } else {
             T::zero()
         }
@@ -77,7 +77,7 @@ impl<T> NumberExtensions<T> for Vec<T> where T: Num + Copy + Clone {
 
     fn gcd(&self) -> T {
         if let Some((first, rest)) = self.split_first() {
-            rest.iter().fold(first.clone(), |acc,v| gcd(acc, v.clone()))
+            rest.iter().fold(*first, |acc,v| gcd(acc, *v))
         } else {
             T::zero()
         }
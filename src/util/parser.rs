@@ -48,7 +48,7 @@ impl Parser {
         let numbers: Vec<_> = self.input.chars().skip(self.position)
             .take_while(|c| c.is_numeric())
             .collect();
-        if numbers.len() == 0 { return Err(format!("Expected to find a number. ('{}':{})", self.input, self.position)) }
+        if numbers.is_empty() { return Err(format!("Expected to find a number. ('{}':{})", self.input, self.position)) }
 
         for char in numbers.iter() {
             result *= 10;
@@ -0,0 +1,316 @@
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::process::{Command as Process, Stdio};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+use crate::util::image::Image;
+
+/// The speeds to choose from, in frames per second.
+const SPEEDS: [f64; 10] = [1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0];
+const DEFAULT_SPEED: usize = 3;
+
+/// How long to wait between looking for keys and frames that are due.
+const POLL: Duration = Duration::from_millis(10);
+
+const HELP: &str = "space play/pause, ←/→ step, +/- speed, g/G first/last, q quit";
+
+/// What a key press asks the player to do.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Command {
+    PlayPause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    First,
+    Last,
+    Quit,
+}
+
+/// Reads the commands from what the terminal sent: letters, or the escape sequences of the arrow,
+/// home and end keys. Anything else is ignored.
+pub fn parse_commands(bytes: &[u8]) -> Vec<Command> {
+    let mut commands = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let command = match bytes[i..] {
+            [0x1b, b'[', key, ..] => {
+                i += 2;
+                match key {
+                    b'C' => Some(Command::Forward),
+                    b'D' => Some(Command::Back),
+                    b'A' => Some(Command::Faster),
+                    b'B' => Some(Command::Slower),
+                    b'H' => Some(Command::First),
+                    b'F' => Some(Command::Last),
+                    _ => None,
+                }
+            }
+            // Escape on its own, q and ctrl-c all stop the replay.
+            [0x1b, ..] | [b'q', ..] | [0x03, ..] => Some(Command::Quit),
+            [b' ', ..] => Some(Command::PlayPause),
+            [b'l' | b'.', ..] => Some(Command::Forward),
+            [b'h' | b',', ..] => Some(Command::Back),
+            [b'+' | b'=', ..] => Some(Command::Faster),
+            [b'-', ..] => Some(Command::Slower),
+            [b'g', ..] => Some(Command::First),
+            [b'G', ..] => Some(Command::Last),
+            _ => None,
+        };
+
+        commands.extend(command);
+        i += 1;
+    }
+
+    commands
+}
+
+/// Replays frames in the terminal, with the keys to pause, step back and forth and change the speed.
+pub struct Player {
+    frames: Vec<(String, Image)>,
+    index: usize,
+    playing: bool,
+    speed: usize,
+    // The part of a frame that is due at the current speed, but not shown yet.
+    due: f64,
+}
+
+impl Player {
+    pub fn new(frames: Vec<(String, Image)>) -> Self {
+        Self { frames, index: 0, playing: true, speed: DEFAULT_SPEED, due: 0.0 }
+    }
+
+    pub fn fps(&self) -> f64 {
+        SPEEDS[self.speed]
+    }
+
+    /// Carries out a command, apart from quitting, which is up to the caller.
+    pub fn apply(&mut self, command: Command) {
+        let last = self.frames.len().saturating_sub(1);
+        match command {
+            Command::PlayPause => {
+                // Playing from the last frame starts over.
+                if !self.playing && self.index == last {
+                    self.index = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Command::Forward => self.step_to((self.index + 1).min(last)),
+            Command::Back => self.step_to(self.index.saturating_sub(1)),
+            Command::Faster => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            Command::Slower => self.speed = self.speed.saturating_sub(1),
+            Command::First => self.step_to(0),
+            Command::Last => self.step_to(last),
+            Command::Quit => {}
+        }
+        self.due = 0.0;
+    }
+
+    fn step_to(&mut self, index: usize) {
+        self.index = index;
+        self.playing = false;
+    }
+
+    /// Moves on as many frames as are due after the elapsed time, stopping at the last one.
+    /// Returns whether another frame is to be shown.
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        if !self.playing {
+            return false;
+        }
+
+        self.due += elapsed.as_secs_f64() * self.fps();
+        let frames = self.due.floor();
+        self.due -= frames;
+
+        let last = self.frames.len().saturating_sub(1);
+        let index = (self.index + frames as usize).min(last);
+        if index == last {
+            self.playing = false;
+        }
+
+        let moved = index != self.index;
+        self.index = index;
+        moved
+    }
+
+    /// The current frame with a status line below it, drawn from the top left of the screen.
+    pub fn screen(&self, title: &str) -> String {
+        let mut screen = "\x1b[H".to_string();
+        let Some((label, image)) = self.frames.get(self.index) else {
+            return screen;
+        };
+
+        // Clearing the rest of every line and of the screen wipes what is left of a larger frame.
+        for line in image.to_ansi().lines() {
+            screen.push_str(line);
+            screen.push_str("\x1b[K\n");
+        }
+        screen.push_str(&format!(
+            "{} - {}\x1b[K\nFrame {}/{} at {} fps, {}\x1b[K\n{}\x1b[K\n\x1b[J",
+            title, label, self.index + 1, self.frames.len(), self.fps(),
+            if self.playing { "playing" } else { "paused" }, HELP,
+        ));
+        screen
+    }
+
+    /// Replays the frames until the user quits. The terminal is used directly, so nothing ends up
+    /// in the output of the days.
+    pub fn play(&mut self, title: &str) -> Result<(), String> {
+        let mut terminal = Terminal::open()?;
+        let mut last = Instant::now();
+        let mut redraw = true;
+
+        loop {
+            for command in parse_commands(&terminal.read()?) {
+                if command == Command::Quit {
+                    return Ok(());
+                }
+                self.apply(command);
+                redraw = true;
+            }
+
+            let now = Instant::now();
+            redraw |= self.tick(now - last);
+            last = now;
+
+            if redraw {
+                terminal.write(&self.screen(title))?;
+                redraw = false;
+            }
+            sleep(POLL);
+        }
+    }
+}
+
+/// Whether there is a terminal to play frames in.
+pub fn has_terminal() -> bool {
+    open_tty().is_ok()
+}
+
+fn open_tty() -> std::io::Result<File> {
+    OpenOptions::new().read(true).write(true).open("/dev/tty")
+}
+
+/// The terminal switched to the alternate screen, and to reading keys as they are pressed without
+/// waiting for them. The settings it had are restored once it is dropped.
+struct Terminal {
+    tty: File,
+    settings: String,
+}
+
+impl Terminal {
+    fn open() -> Result<Self, String> {
+        let tty = open_tty().map_err(|e| format!("Could not open the terminal: {}", e))?;
+        let settings = stty(&tty, &["-g"])?;
+        stty(&tty, &["-icanon", "-echo", "-isig", "min", "0", "time", "0"])?;
+
+        let mut terminal = Self { tty, settings: settings.trim().to_string() };
+        terminal.write("\x1b[?1049h\x1b[?25l\x1b[2J")?;
+        Ok(terminal)
+    }
+
+    /// The bytes of the keys pressed since the last read, if any.
+    fn read(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer = [0; 64];
+        let count = self.tty.read(&mut buffer).map_err(|e| format!("Could not read from the terminal: {}", e))?;
+        Ok(buffer[..count].to_vec())
+    }
+
+    fn write(&mut self, text: &str) -> Result<(), String> {
+        self.tty.write_all(text.as_bytes())
+            .and_then(|_| self.tty.flush())
+            .map_err(|e| format!("Could not write to the terminal: {}", e))
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        // Nothing more to do when restoring fails; the terminal is best reset by hand then.
+        let _ = self.write("\x1b[?25h\x1b[?1049l");
+        let _ = stty(&self.tty, &[self.settings.as_str()]);
+    }
+}
+
+/// Runs `stty` on the terminal, returning what it printed.
+fn stty(tty: &File, args: &[&str]) -> Result<String, String> {
+    let stdin = tty.try_clone().map_err(|e| format!("Could not open the terminal: {}", e))?;
+    let output = Process::new("stty").args(args).stdin(Stdio::from(stdin)).stderr(Stdio::inherit()).output()
+        .map_err(|e| format!("Could not run stty: {}", e))?;
+
+    match output.status.success() {
+        true => Ok(String::from_utf8_lossy(&output.stdout).to_string()),
+        false => Err(format!("stty {} failed", args.join(" "))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::util::image::{Color, Image};
+    use crate::util::player::{parse_commands, Command, Player};
+
+    fn player(count: usize) -> Player {
+        Player::new((0..count).map(|i| (format!("Step {}", i), Image::new(1, 2, Color::gray(i as u8)))).collect())
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(parse_commands(b" l\x1b[C\x1b[D+-gGq"), vec![
+            Command::PlayPause, Command::Forward, Command::Forward, Command::Back,
+            Command::Faster, Command::Slower, Command::First, Command::Last, Command::Quit,
+        ]);
+        assert_eq!(parse_commands(b"x\x1b[Zh\x1b"), vec![Command::Back, Command::Quit]);
+        assert_eq!(parse_commands(b""), vec![]);
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut player = player(5);
+        assert_eq!(player.fps(), 10.0);
+        assert!(!player.tick(Duration::from_millis(50)));
+        assert!(player.tick(Duration::from_millis(50)));
+        assert_eq!(player.index, 1);
+
+        player.apply(Command::Faster);
+        assert!(player.tick(Duration::from_millis(100)));
+        assert_eq!(player.index, 3);
+
+        // Playing stops at the last frame, and starts over from there.
+        assert!(player.tick(Duration::from_secs(10)));
+        assert_eq!((player.index, player.playing), (4, false));
+        player.apply(Command::PlayPause);
+        assert_eq!((player.index, player.playing), (0, true));
+    }
+
+    #[test]
+    fn test_step() {
+        let mut player = player(3);
+        player.apply(Command::Back);
+        assert_eq!((player.index, player.playing), (0, false));
+        assert!(!player.tick(Duration::from_secs(1)));
+
+        player.apply(Command::Forward);
+        player.apply(Command::Forward);
+        player.apply(Command::Forward);
+        assert_eq!(player.index, 2);
+        player.apply(Command::First);
+        assert_eq!(player.index, 0);
+        player.apply(Command::Last);
+        assert_eq!(player.index, 2);
+
+        for _ in 0..20 {
+            player.apply(Command::Slower);
+        }
+        assert_eq!(player.fps(), 1.0);
+    }
+
+    #[test]
+    fn test_screen() {
+        let mut player = player(3);
+        player.apply(Command::Forward);
+        let screen = player.screen("Day 6 part 1");
+        assert!(screen.starts_with("\x1b[H\x1b[38;2;1;1;1;48;2;1;1;1m▀\x1b[0m\x1b[K\n"));
+        assert!(screen.contains("Day 6 part 1 - Step 1\x1b[K\nFrame 2/3 at 10 fps, paused"));
+    }
+}
@@ -0,0 +1,74 @@
+use regex::RegexBuilder;
+
+/// What we can take from a saved puzzle description: its title and the first example input.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct PuzzlePage {
+    pub title: Option<String>,
+    pub example: Option<String>,
+}
+
+impl PuzzlePage {
+    pub fn from_html(html: &str) -> Result<Self, String> {
+        let title_regex = RegexBuilder::new("<h2>(.*?)</h2>").build().map_err(|e| e.to_string())?;
+        // The examples are the `<pre><code>` blocks; small inline `<code>` bits are not.
+        let example_regex = RegexBuilder::new("<pre><code>(.*?)</code></pre>").dot_matches_new_line(true).build().map_err(|e| e.to_string())?;
+
+        let title = title_regex.captures(html)
+            .map(|caps| strip_tags(&caps[1]).trim_matches(|c: char| c == '-' || c.is_whitespace()).to_string());
+        let example = example_regex.captures(html)
+            .map(|caps| strip_tags(&caps[1]).trim_end().to_string());
+
+        Ok(Self { title, example })
+    }
+}
+
+/// Removes the markup from a piece of html, keeping the text (and its whitespace) only.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::puzzle_page::{strip_tags, PuzzlePage};
+
+    const TEST_PAGE: &str = "\
+        <main><article class=\"day-desc\"><h2>--- Day 5: Print Queue ---</h2>\n\
+        <p>The notation <code>X|Y</code> means that...</p>\n\
+        <pre><code>47|53\n\
+        97|13\n\
+        \n\
+        <em>75</em>,47,61\n\
+        </code></pre>\n\
+        <pre><code>other</code></pre></article></main>\
+    ";
+
+    #[test]
+    fn test_from_html() {
+        assert_eq!(PuzzlePage::from_html(TEST_PAGE), Ok(PuzzlePage {
+            title: Some("Day 5: Print Queue".to_string()),
+            example: Some("47|53\n97|13\n\n75,47,61".to_string()),
+        }));
+        assert_eq!(PuzzlePage::from_html("<html></html>"), Ok(PuzzlePage::default()));
+    }
+
+    #[test]
+    fn test_strip_tags() {
+        assert_eq!(strip_tags("<p>a <em>b</em>\n c</p>"), "a b\n c");
+        assert_eq!(strip_tags("x &lt;= y &amp;&amp; &quot;z&quot;"), "x <= y && \"z\"");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::util::geometry::{Bounds, DenseGrid, Grid, Point};
+use crate::util::image::{Color, Image};
+
+/// Points to draw over a rendered grid, like a path through it.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Overlay {
+    pub points: Vec<Point>,
+    pub color: Color,
+}
+
+impl Overlay {
+    pub fn new<I: IntoIterator<Item = Point>>(points: I, color: Color) -> Self {
+        Self { points: points.into_iter().collect(), color }
+    }
+}
+
+/// The grids a renderer can draw.
+pub trait Drawable<T> {
+    fn bounds(&self) -> Bounds;
+
+    /// An image with a pixel per cell, colored by the palette; black where a cell is not set.
+    fn draw(&self, palette: &dyn Fn(&T) -> Color) -> Image;
+}
+
+impl<T> Drawable<T> for Grid<T> where T: Clone {
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    fn draw(&self, palette: &dyn Fn(&T) -> Color) -> Image {
+        self.to_image(palette, Color::BLACK)
+    }
+}
+
+impl<T> Drawable<T> for DenseGrid<T> {
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    fn draw(&self, palette: &dyn Fn(&T) -> Color) -> Image {
+        self.to_image(palette, Color::BLACK)
+    }
+}
+
+/// Draws grids as images, with a pixel per cell colored by the palette. The image sinks take care
+/// of scaling the images up and of putting them together into animations.
+pub struct Renderer<T> {
+    palette: Box<dyn Fn(&T) -> Color>,
+}
+
+impl<T> Renderer<T> {
+    pub fn new<F>(palette: F) -> Self where F: Fn(&T) -> Color + 'static {
+        Self { palette: Box::new(palette) }
+    }
+
+    /// Renders the grid, with the overlays drawn over it in order. Overlay points outside the
+    /// grid are left out.
+    pub fn render<G: Drawable<T>>(&self, grid: &G, overlays: &[Overlay]) -> Image {
+        let mut image = grid.draw(&self.palette);
+
+        let bounds = grid.bounds();
+        for overlay in overlays {
+            for p in overlay.points.iter().filter(|p| bounds.contains(p)) {
+                image.set((p.x - bounds.left) as usize, (p.y - bounds.top) as usize, overlay.color);
+            }
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::geometry::{DenseGrid, Grid, Point};
+    use crate::util::image::Color;
+    use crate::util::render::{Overlay, Renderer};
+
+    fn renderer() -> Renderer<char> {
+        Renderer::new(|c: &char| if *c == '#' { Color::WHITE } else { Color::gray(64) })
+    }
+
+    #[test]
+    fn test_render() {
+        let grid: Grid<char> = "#.\n.#".parse().unwrap();
+        let image = renderer().render(&grid, &[]);
+        assert_eq!(image.pixels(), [Color::WHITE, Color::gray(64), Color::gray(64), Color::WHITE]);
+
+        let dense: DenseGrid<char> = "#.\n.#".parse().unwrap();
+        assert_eq!(renderer().render(&dense, &[]), image);
+    }
+
+    #[test]
+    fn test_render_overlays() {
+        let grid: Grid<char> = "#.\n.#".parse().unwrap();
+        let path = Overlay::new([(0, 1).into(), (1, 1).into(), (5, 5).into()], Color::RED);
+        let end = Overlay::new([Point::from((1, 1))], Color::GREEN);
+
+        let image = renderer().render(&grid, &[path, end]);
+        assert_eq!(image.pixels(), [Color::WHITE, Color::gray(64), Color::RED, Color::GREEN]);
+    }
+
+    #[test]
+    fn test_render_unset_cells() {
+        let mut grid: Grid<char> = Grid::default();
+        grid.set((0, 0).into(), '#');
+        grid.set((1, 1).into(), '.');
+
+        assert_eq!(renderer().render(&grid, &[]).pixels(), [Color::WHITE, Color::BLACK, Color::BLACK, Color::gray(64)]);
+    }
+}
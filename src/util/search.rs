@@ -0,0 +1,384 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use num_bigint::BigUint;
+use num_traits::One;
+use crate::util::geometry::{Cells, Directions, Point};
+
+/// What a search found: the cost of the cheapest way to every state it reached, and all states
+/// each of them is reached from on such a cheapest way.
+///
+/// A search with a goal stops once no cheaper goal can be found, so it knows all goal states at
+/// the lowest cost, and every shortest path to them. Without a goal it covers everything reachable.
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S> Search<S> where S: Eq + Hash + Clone {
+    fn new(start: S) -> Self {
+        Self { distances: HashMap::from([(start, 0)]), predecessors: HashMap::new(), goals: vec![] }
+    }
+
+    /// Records reaching a state from another at the cost. Returns whether it is the cheapest way
+    /// there so far, which is when the state needs to be (re)visited.
+    fn reach(&mut self, from: &S, to: S, cost: usize) -> bool {
+        match self.distances.get(&to) {
+            Some(known) if cost > *known => false,
+            Some(known) if cost == *known => {
+                // A free step can lead back to a state on the way here, which is no way to get here.
+                let free = self.distance(from) == Some(cost);
+                if !(free && self.leads_to(&to, from)) {
+                    self.predecessors.entry(to).or_default().push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(to.clone(), cost);
+                self.predecessors.insert(to, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Whether the state is on one of the cheapest paths to the other one, or is that one.
+    fn leads_to(&self, state: &S, other: &S) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![other];
+        while let Some(current) = stack.pop() {
+            if current == state {
+                return true;
+            }
+            if seen.insert(current) {
+                stack.extend(self.predecessors(current));
+            }
+        }
+
+        false
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// The states a state is reached from on its cheapest ways; none for the start. Following these
+    /// back never goes round in circles, not even through free steps.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], |p| p.as_slice())
+    }
+
+    /// The goal states that were reached at the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of reaching a goal, if any was reached.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// One of the cheapest paths from the start to the state, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// One of the cheapest paths to the first goal found.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// All states on any of the cheapest paths from the start to the given states.
+    pub fn states_on_paths_to(&self, targets: &[S]) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut stack: Vec<S> = targets.iter().filter(|t| self.distances.contains_key(t)).cloned().collect();
+
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        states
+    }
+
+    /// The number of different cheapest paths from the start to the given states, added up.
+    pub fn count_paths_to(&self, targets: &[S]) -> BigUint {
+        // A state is counted once its predecessors are. Those can be as close to the start as the
+        // state itself after a free step, so this goes depth first instead of by distance.
+        let mut counts: HashMap<&S, BigUint> = HashMap::new();
+        let mut stack: Vec<(&S, bool)> = targets.iter().filter(|t| self.distances.contains_key(t)).map(|t| (t, false)).collect();
+
+        while let Some((state, predecessors_counted)) = stack.pop() {
+            if counts.contains_key(state) {
+                continue;
+            }
+
+            let predecessors = self.predecessors(state);
+            if predecessors_counted {
+                let count = match predecessors {
+                    [] => BigUint::one(),
+                    predecessors => predecessors.iter().map(|p| &counts[p]).sum(),
+                };
+                counts.insert(state, count);
+            } else {
+                stack.push((state, true));
+                stack.extend(predecessors.iter().map(|p| (p, false)));
+            }
+        }
+
+        targets.iter().filter_map(|t| counts.get(t)).sum()
+    }
+}
+
+/// Breadth first search, for when every step costs the same.
+pub fn bfs<S, I, N, G>(start: S, mut successors: N, mut is_goal: G) -> Search<S>
+    where S: Eq + Hash + Clone, N: FnMut(&S) -> I, I: IntoIterator<Item = S>, G: FnMut(&S) -> bool
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = search.distances[&state];
+        // The states are taken in order of distance, so there are no more goals this close.
+        if search.goal_distance().is_some_and(|goal_distance| distance > goal_distance) {
+            break;
+        }
+        if is_goal(&state) {
+            search.goals.push(state.clone());
+        }
+
+        for next in successors(&state) {
+            if search.reach(&state, next.clone(), distance + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's shortest paths, with successors that come with the cost of stepping to them. Steps
+/// may be free; stepping back for free to a state on the way is not counted as another way there.
+pub fn dijkstra<S, I, N, G>(start: S, successors: N, is_goal: G) -> Search<S>
+    where S: Eq + Hash + Clone, N: FnMut(&S) -> I, I: IntoIterator<Item = (S, usize)>, G: FnMut(&S) -> bool
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search: Dijkstra, looking at the states that seem closest to a goal first. The heuristic
+/// estimates the cost from a state to a goal, and may never be more than the cost of a step plus
+/// the estimate from where that step leads.
+pub fn astar<S, I, N, H, G>(start: S, mut successors: N, heuristic: H, mut is_goal: G) -> Search<S>
+    where S: Eq + Hash + Clone, N: FnMut(&S) -> I, I: IntoIterator<Item = (S, usize)>, H: Fn(&S) -> usize, G: FnMut(&S) -> bool
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = BinaryHeap::from([Entry { priority: heuristic(&start), cost: 0, state: start }]);
+
+    while let Some(Entry { priority, cost, state }) = queue.pop() {
+        if cost > search.distances[&state] {
+            continue; // A cheaper way here was found after this one was queued.
+        }
+        // States that seem this close may still lead to the goals found, keep going until they are done.
+        if search.goal_distance().is_some_and(|goal_distance| priority > goal_distance) {
+            break;
+        }
+        if is_goal(&state) {
+            search.goals.push(state.clone());
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if search.reach(&state, next.clone(), next_cost) {
+                queue.push(Entry { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+            }
+        }
+    }
+
+    search
+}
+
+struct Entry<S> {
+    priority: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap pops the greatest entry, so the lowest priority has to compare as the greatest.
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Breadth first search through a grid, stepping up, down, left and right between passable cells.
+pub fn bfs_grid<G, T, P, F>(grid: &G, start: Point, passable: P, is_goal: F) -> Search<Point>
+    where G: Cells<T>, P: Fn(&T) -> bool, F: FnMut(&Point) -> bool
+{
+    let passable = &passable;
+    bfs(start, |p| p.neighbors(Directions::NonDiagonal).filter(move |n| grid.cell(n).is_some_and(passable)), is_goal)
+}
+
+/// A* search through a grid to the goal, stepping up, down, left and right between passable cells.
+pub fn astar_grid<G, T, P>(grid: &G, start: Point, goal: Point, passable: P) -> Search<Point>
+    where G: Cells<T>, P: Fn(&T) -> bool
+{
+    let passable = &passable;
+    let successors = |p: &Point| p.neighbors(Directions::NonDiagonal)
+        .filter(move |n| grid.cell(n).is_some_and(passable))
+        .map(|n| (n, 1));
+
+    astar(start, successors, |p| p.manhattan_distance(&goal) as usize, |p| *p == goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use num_bigint::BigUint;
+    use crate::util::geometry::{DenseGrid, Grid, Point};
+    use crate::util::search::{astar, astar_grid, bfs, bfs_grid, dijkstra};
+
+    const MAZE: &str = "\
+        S..#\n\
+        .#..\n\
+        ...E\n\
+    ";
+
+    #[test]
+    fn test_bfs() {
+        // Counting down by subtracting 1 or halving even numbers.
+        let successors = |n: &i32| {
+            let n = *n;
+            [n - 1, n / 2].into_iter().filter(move |m| *m >= 0 && (m + 1 == n || m * 2 == n))
+        };
+        let search = bfs(10, successors, |n| *n == 0);
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(search.path(), Some(vec![10, 5, 4, 2, 1, 0]));
+        assert_eq!(search.predecessors(&2), [4]);
+        assert_eq!(search.predecessors(&10), [] as [i32; 0]);
+
+        let search = bfs(3, |n| if *n < 10 { vec![n + 1] } else { vec![] }, |n| *n == 0);
+        assert_eq!(search.goal_distance(), None);
+        assert_eq!(search.path(), None);
+    }
+
+    #[test]
+    fn test_dijkstra_all_shortest_paths() {
+        // Two ways of the same cost from a to d, and a more expensive one.
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 1), ('c', 2), ('e', 1)],
+            'b' => vec![('d', 2)],
+            'c' => vec![('d', 1)],
+            'e' => vec![('d', 5)],
+            _ => vec![],
+        };
+        let search = dijkstra('a', edges, |n| *n == 'd');
+        assert_eq!(search.goals(), ['d']);
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.predecessors(&'d').iter().collect::<HashSet<_>>(), HashSet::from([&'b', &'c']));
+        assert_eq!(search.states_on_paths_to(&['d']), HashSet::from(['a', 'b', 'c', 'd']));
+
+        assert_eq!(search.count_paths_to(&['d']), BigUint::from(2u32));
+        assert_eq!(search.count_paths_to(&['a']), BigUint::from(1u32));
+        assert_eq!(search.count_paths_to(&['z']), BigUint::from(0u32));
+
+        let everything = dijkstra('a', edges, |_| false);
+        assert_eq!(everything.distance(&'e'), Some(1));
+        assert_eq!(everything.distances().len(), 5);
+    }
+
+    #[test]
+    fn test_dijkstra_free_steps() {
+        // a, b and c are free steps apart, some back and to themselves; d costs a step from a or c.
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 0), ('c', 0), ('d', 1)],
+            'b' => vec![('a', 0), ('b', 0), ('c', 0)],
+            'c' => vec![('c', 0), ('d', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra('a', edges, |n| *n == 'd');
+        assert_eq!(search.goal_distance(), Some(1));
+        assert_eq!(search.distance(&'c'), Some(0));
+        assert_eq!(search.predecessors(&'a'), [] as [char; 0]);
+        assert_eq!(search.predecessors(&'b'), ['a']);
+        assert_eq!(search.predecessors(&'c').iter().collect::<HashSet<_>>(), HashSet::from([&'a', &'b']));
+
+        assert_eq!(search.count_paths_to(&['c']), BigUint::from(2u32));
+        assert_eq!(search.count_paths_to(&['d']), BigUint::from(3u32));
+        assert_eq!(search.states_on_paths_to(&['d']), HashSet::from(['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Every path right and down through a 40 by 40 grid, far more than fit in a u64.
+        let successors = |&(x, y): &(u32, u32)| [(x + 1, y), (x, y + 1)].into_iter().filter(|(x, y)| *x < 40 && *y < 40);
+        let search = bfs((0, 0), successors, |_| false);
+        let paths = search.count_paths_to(&[(39, 39)]);
+        assert_eq!(paths.to_string(), "27217014869199032015600");
+        assert_eq!(search.count_paths_to(&[(1, 1), (2, 0)]), BigUint::from(3u32));
+
+        let search = bfs((0, 0), successors, |p| *p == (2, 2));
+        assert_eq!(search.count_paths_to(search.goals()), BigUint::from(6u32));
+        assert_eq!(search.path().map(|path| (path.len(), path[0])), Some((5, (0, 0))));
+        assert_eq!(search.predecessors(&(2, 2)).len(), 2);
+    }
+
+    #[test]
+    fn test_astar() {
+        let successors = |p: &(i32, i32)| [(p.0 + 1, p.1), (p.0, p.1 + 1), (p.0 - 1, p.1)].into_iter()
+            .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y) && !(*y == 5 && *x < 9))
+            .map(|p| (p, 1));
+        let heuristic = |p: &(i32, i32)| (p.0.abs_diff(0) + p.1.abs_diff(9)) as usize;
+
+        let search = astar((0, 0), successors, heuristic, |p| *p == (0, 9));
+        assert_eq!(search.goal_distance(), Some(27));
+        assert_eq!(search.goal_distance(), dijkstra((0, 0), successors, |p| *p == (0, 9)).goal_distance());
+        assert!(search.distances().len() < 100);
+    }
+
+    #[test]
+    fn test_grid_search() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(|c| *c == 'S').unwrap();
+        let end = grid.find(|c| *c == 'E').unwrap();
+
+        let search = bfs_grid(&grid, start, |c| *c != '#', |p| *p == end);
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(search.states_on_paths_to(&[end]).len(), 10);
+
+        let dense: DenseGrid<char> = MAZE.parse().unwrap();
+        let search = astar_grid(&dense, start, end, |c| *c != '#');
+        let path = search.path().unwrap();
+        assert_eq!((path.len(), path[0], path[5]), (6, start, end));
+
+        let walled = astar_grid(&dense, start, end, |c| *c == '.');
+        assert_eq!(walled.path(), None);
+        assert_eq!(bfs_grid(&dense, start, |c| *c != '#', |_| false).distance(&Point::from((2, 1))), Some(3));
+    }
+}
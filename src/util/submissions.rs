@@ -0,0 +1,180 @@
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use crate::util::aoc_client::Feedback;
+use crate::util::input::year_dir;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Attempt {
+    pub puzzle: usize,
+    pub feedback: Feedback,
+    pub answer: String,
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The answer goes last, so it is free to contain spaces.
+        write!(f, "{} {} {}", self.puzzle, self.feedback, self.answer)
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.splitn(3, ' ').collect();
+        let [puzzle, feedback, answer] = parts[..] else {
+            return Err(format!("Expected '<puzzle> <feedback> <answer>', got '{}'", s));
+        };
+        let puzzle = puzzle.parse().map_err(|_| format!("Invalid puzzle '{}' in submissions", puzzle))?;
+
+        Ok(Self { puzzle, feedback: feedback.parse()?, answer: answer.to_string() })
+    }
+}
+
+/// Every answer we ever submitted for a day, with the feedback it got.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct Submissions {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Submissions {
+    fn judged(&self, puzzle: usize) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |a| a.puzzle == puzzle && a.feedback.is_judged())
+    }
+
+    /// The exclusive bounds the answer must be within, following from earlier too-low and too-high
+    /// feedback. Only numeric answers take part in this.
+    pub fn bounds(&self, puzzle: usize) -> (Option<u128>, Option<u128>) {
+        let numbers = |feedback: Feedback| self.judged(puzzle)
+            .filter(move |a| a.feedback == feedback)
+            .filter_map(|a| a.answer.parse::<u128>().ok());
+
+        (numbers(Feedback::TooLow).max(), numbers(Feedback::TooHigh).min())
+    }
+
+    /// Checks whether submitting the answer makes any sense, given the earlier attempts.
+    pub fn check(&self, puzzle: usize, answer: &str) -> Result<(), String> {
+        if answer.trim().is_empty() {
+            return Err(format!("The answer to puzzle {} is empty", puzzle));
+        }
+        if let Some(correct) = self.judged(puzzle).find(|a| a.feedback == Feedback::Correct) {
+            return Err(format!("Puzzle {} was already solved with {}", puzzle, correct.answer));
+        }
+        if let Some(attempt) = self.judged(puzzle).find(|a| a.answer == answer) {
+            return Err(format!("{} was already submitted for puzzle {}, and was {}", answer, puzzle, attempt.feedback));
+        }
+
+        if let Ok(value) = answer.parse::<u128>() {
+            match self.bounds(puzzle) {
+                (Some(low), _) if value <= low => return Err(format!("{} is too low, the answer is higher than {}", answer, low)),
+                (_, Some(high)) if value >= high => return Err(format!("{} is too high, the answer is lower than {}", answer, high)),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Submissions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Lines are not trimmed, that would take the separator off an empty answer.
+        let attempts = s.lines().filter(|l| !l.trim().is_empty()).map(|l| l.parse()).collect::<Result<_, _>>()?;
+        Ok(Self { attempts })
+    }
+}
+
+fn submissions_dir(year: i32) -> String {
+    format!("{}/submissions", year_dir(year))
+}
+
+fn submissions_path(year: i32, day: i32) -> String {
+    format!("{}/day{:02}.txt", submissions_dir(year), day)
+}
+
+pub fn read_submissions(year: i32, day: i32) -> Result<Submissions, String> {
+    let path = submissions_path(year, day);
+
+    if !Path::new(&path).exists() {
+        return Ok(Submissions::default());
+    }
+
+    read_to_string(&path)
+        .map_err(|e| format!("Could not read submissions for day {}: {}", day, e))?
+        .parse()
+}
+
+/// Appends the attempt to the submission log of the day.
+pub fn record_attempt(year: i32, day: i32, attempt: &Attempt) -> Result<(), String> {
+    let dir = submissions_dir(year);
+    create_dir_all(&dir).map_err(|e| format!("Could not create {}: {}", dir, e))?;
+
+    let path = submissions_path(year, day);
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)
+        .map_err(|e| format!("Could not open {}: {}", path, e))?;
+    writeln!(file, "{}", attempt).map_err(|e| format!("Could not write {}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::aoc_client::Feedback;
+    use crate::util::submissions::{Attempt, Submissions};
+
+    const TEST_LOG: &str = "\
+        1 too-high 500\n\
+        1 rate-limited 100\n\
+        1 too-low 50\n\
+        1 wrong 200\n\
+        1 too-low 20\n\
+        2 correct ab,cd\n\
+        2 wrong Freebie for Christmas~\n\
+    ";
+
+    #[test]
+    fn test_parse_submissions() {
+        let submissions: Submissions = TEST_LOG.parse().unwrap();
+
+        assert_eq!(submissions.attempts.len(), 7);
+        assert_eq!(submissions.attempts[6], Attempt { puzzle: 2, feedback: Feedback::Wrong, answer: "Freebie for Christmas~".to_string() });
+        assert!("1 correct".parse::<Submissions>().is_err());
+        assert!("1 maybe 42".parse::<Submissions>().is_err());
+    }
+
+    #[test]
+    fn test_attempt_round_trip() {
+        let attempt = Attempt { puzzle: 1, feedback: Feedback::TooLow, answer: "1234".to_string() };
+        assert_eq!(attempt.to_string().parse(), Ok(attempt));
+
+        let empty = Attempt { puzzle: 1, feedback: Feedback::Wrong, answer: "".to_string() };
+        let log = format!("{}\n{}\n", empty, Attempt { answer: " 42 ".to_string(), ..empty.clone() });
+        let submissions: Submissions = log.parse().unwrap();
+        assert_eq!(submissions.attempts[0], empty);
+        assert_eq!(submissions.attempts[1].answer, " 42 ");
+    }
+
+    #[test]
+    fn test_bounds() {
+        let submissions: Submissions = TEST_LOG.parse().unwrap();
+
+        assert_eq!(submissions.bounds(1), (Some(50), Some(500)));
+        assert_eq!(submissions.bounds(2), (None, None));
+    }
+
+    #[test]
+    fn test_check() {
+        let submissions: Submissions = TEST_LOG.parse().unwrap();
+
+        assert_eq!(submissions.check(1, "100"), Ok(()));
+        assert!(submissions.check(1, "200").is_err());
+        assert!(submissions.check(1, "50").is_err());
+        assert!(submissions.check(1, "600").is_err());
+        assert!(submissions.check(2, "something else").is_err());
+        assert_eq!(Submissions::default().check(1, "42"), Ok(()));
+        assert_eq!(Submissions::default().check(1, " "), Err("The answer to puzzle 1 is empty".to_string()));
+    }
+}